@@ -0,0 +1,132 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use ::std::{
+    collections::HashMap,
+    sync::RwLock,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    namespace::{NamespaceAlias, NamespaceId, NamespaceInfo},
+    transaction::Height,
+    AssetId,
+};
+
+/// How long the chain height of an [AliasCache] is trusted before it is fetched again,
+/// about the time between two blocks.
+pub const ALIAS_CACHE_HEIGHT_TTL: Duration = Duration::from_secs(15);
+
+#[derive(Debug, Clone)]
+struct AliasCacheEntry {
+    alias: NamespaceAlias,
+    /// The chain height known when the alias was resolved.
+    resolved_at: u64,
+    /// The height at which the namespace expires.
+    end_height: u64,
+}
+
+#[derive(Debug, Default)]
+struct AliasCacheState {
+    height: u64,
+    /// When the height was last updated.
+    updated_at: Option<Instant>,
+    entries: HashMap<u64, AliasCacheEntry>,
+}
+
+/// A height-aware cache of resolved namespace aliases, shared by every `ResolverRoutes`
+/// created from the same `SiriusClient`.
+///
+/// An alias is dropped once the chain reaches the end height of its namespace, the
+/// resolver updates the height when it is older than `ALIAS_CACHE_HEIGHT_TTL`. Aliases that
+/// are linked or unlinked while the namespace is active are not noticed by the cache, call
+/// `invalidate` when such a change is observed.
+#[derive(Debug, Default)]
+pub struct AliasCache {
+    state: RwLock<AliasCacheState>,
+}
+
+impl AliasCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The chain height the cache was last updated with.
+    pub fn height(&self) -> Height {
+        Height::new(self.state.read().unwrap().height)
+    }
+
+    /// Whether the height is unknown or was updated more than [ttl] ago.
+    pub fn is_height_stale(&self, ttl: Duration) -> bool {
+        match self.state.read().unwrap().updated_at {
+            Some(updated_at) => updated_at.elapsed() >= ttl,
+            None => true,
+        }
+    }
+
+    /// Returns the cached alias of the namespace, if any.
+    pub fn get(&self, namespace_id: NamespaceId) -> Option<NamespaceAlias> {
+        let state = self.state.read().unwrap();
+        state
+            .entries
+            .get(&namespace_id.to_u64())
+            .filter(|entry| state.height < entry.end_height)
+            .map(|entry| entry.alias.clone())
+    }
+
+    /// Caches the alias of the given namespace.
+    pub fn insert(&self, namespace_info: &NamespaceInfo) {
+        let mut state = self.state.write().unwrap();
+        let entry = AliasCacheEntry {
+            alias: namespace_info.alias.clone(),
+            resolved_at: state.height,
+            end_height: *namespace_info.end_height,
+        };
+        state
+            .entries
+            .insert(namespace_info.namespace_id.to_u64(), entry);
+    }
+
+    /// Advances the cache to the given chain [height].
+    ///
+    /// Aliases of expired namespaces are dropped. A [height] lower than the known height
+    /// is treated as a rollback and drops every alias resolved after it.
+    pub fn update_height(&self, height: Height) {
+        let height = *height;
+        let mut state = self.state.write().unwrap();
+
+        if height < state.height {
+            state.entries.retain(|_, entry| entry.resolved_at <= height);
+        }
+
+        state.height = height;
+        state.updated_at = Some(Instant::now());
+        state.entries.retain(|_, entry| height < entry.end_height);
+    }
+
+    /// Drops the cached alias of the given namespace.
+    pub fn invalidate(&self, namespace_id: NamespaceId) {
+        self.state
+            .write()
+            .unwrap()
+            .entries
+            .remove(&namespace_id.to_u64());
+    }
+
+    /// Drops every cached alias.
+    pub fn clear(&self) {
+        self.state.write().unwrap().entries.clear();
+    }
+
+    /// The number of cached aliases.
+    pub fn len(&self) -> usize {
+        self.state.read().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -121,12 +121,12 @@ pub(crate) struct AccountPropertiesTransactionDto {
 
 #[typetag::serde]
 impl TransactionDto for AccountPropertiesTransactionInfoDto {
-    fn compact(&self) -> Result<Box<dyn Transaction>> {
+    fn compact(&self, epoch: i64) -> Result<Box<dyn Transaction>> {
         let dto = self.transaction.clone();
 
         let info = self.meta.compact()?;
 
-        let abs_transaction = dto.r#abstract.compact(info)?;
+        let abs_transaction = dto.r#abstract.compact(info, epoch)?;
 
         if dto.property_type & AccountPropertyType::AllowAddress.value() != 0 {
             let modifications: Vec<AccountPropertiesAddressModification> = dto
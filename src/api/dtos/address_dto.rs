@@ -31,12 +31,12 @@ pub(crate) struct AddressMetadataTransactionInfoDto {
 
 #[typetag::serde]
 impl TransactionDto for AddressMetadataTransactionInfoDto {
-    fn compact(&self) -> crate::models::Result<Box<dyn Transaction>> {
+    fn compact(&self, epoch: i64) -> crate::models::Result<Box<dyn Transaction>> {
         let dto = self.transaction.clone();
 
         let info = self.meta.compact()?;
 
-        let metadata_transaction = dto.metadata_transaction.compact(info)?;
+        let metadata_transaction = dto.metadata_transaction.compact(info, epoch)?;
 
         let address = Address::from_encoded(&dto.address)?;
 
@@ -35,14 +35,14 @@ pub(crate) struct AggregateTransactionInfoDto {
 
 #[typetag::serde]
 impl TransactionDto for AggregateTransactionInfoDto {
-    fn compact(&self) -> Result<Box<dyn Transaction>> {
+    fn compact(&self, epoch: i64) -> Result<Box<dyn Transaction>> {
         let dto = self.transaction.clone();
 
         let info = self.meta.compact()?;
 
         let txs_dto = map_aggregate_transactions_dto(dto.transactions)?;
 
-        let abs_transaction = dto.r#abstract.compact(info)?;
+        let abs_transaction = dto.r#abstract.compact(info, epoch)?;
 
         let mut cosignatures: Vec<Cosignature> = vec![];
         if let Some(c) = dto.cosignatures {
@@ -54,7 +54,7 @@ impl TransactionDto for AggregateTransactionInfoDto {
 
         let mut inner_transactions: Vec<Box<dyn Transaction>> = Vec::with_capacity(txs_dto.len());
         for transaction_info_dto in txs_dto {
-            inner_transactions.push(transaction_info_dto.compact()?);
+            inner_transactions.push(transaction_info_dto.compact(epoch)?);
         }
 
         Ok(Box::new(AggregateTransaction {
@@ -70,7 +70,7 @@ pub(crate) struct AddressAliasTransactionInfoDto {
 
 #[typetag::serde]
 impl TransactionDto for AddressAliasTransactionInfoDto {
-    fn compact(&self) -> Result<Box<dyn Transaction>> {
+    fn compact(&self, epoch: i64) -> Result<Box<dyn Transaction>> {
         let dto = self.transaction.clone();
 
         let info = self.meta.compact()?;
@@ -79,7 +79,7 @@ impl TransactionDto for AddressAliasTransactionInfoDto {
 
         let address = Address::from_encoded(&address_encoded)?;
 
-        let abs_transaction = dto.r#abstract.compact(info)?;
+        let abs_transaction = dto.r#abstract.compact(info, epoch)?;
 
         Ok(Box::new(AddressAliasTransaction {
             alias_transaction: AliasTransaction {
@@ -101,7 +101,7 @@ pub(crate) struct MosaicAliasTransactionInfoDto {
 
 #[typetag::serde]
 impl TransactionDto for MosaicAliasTransactionInfoDto {
-    fn compact(&self) -> Result<Box<dyn Transaction>> {
+    fn compact(&self, epoch: i64) -> Result<Box<dyn Transaction>> {
         let dto = self.transaction.clone();
 
         let info = self.meta.compact()?;
@@ -110,7 +110,7 @@ impl TransactionDto for MosaicAliasTransactionInfoDto {
 
         let mosaic_id = MosaicId::from(mosaic_id_dto.compact());
 
-        let abs_transaction = dto.r#abstract.compact(info)?;
+        let abs_transaction = dto.r#abstract.compact(info, epoch)?;
 
         Ok(Box::new(MosaicAliasTransaction {
             alias_transaction: AliasTransaction {
@@ -52,9 +52,11 @@ pub(crate) struct BlockDto {
 impl BlockDto {
     pub fn compact(
         self,
+        hash: String,
         generation_hash: String,
         num_transactions: u64,
         total_fee: [u32; 2],
+        epoch: i64,
     ) -> Result<BlockInfo> {
         let dto = self;
 
@@ -107,10 +109,12 @@ impl BlockDto {
             version,
             ver_type: dto._type,
             height: dto.height.compact(),
-            timestamp: BlockchainTimestamp::new(*dto.timestamp.compact() as i64).to_timestamp(),
+            timestamp: BlockchainTimestamp::new(*dto.timestamp.compact() as i64)
+                .to_timestamp_with_epoch(epoch),
             difficulty: dto.difficulty.compact(),
             num_transactions,
             fee_multiplier,
+            hash: HashValue::from_str(&hash)?,
             generation_hash: HashValue::from_str(&generation_hash)?,
             previous_block_hash: HashValue::from_str(&dto.previous_block_hash)?,
             block_transactions_hash: HashValue::from_str(&dto.block_transactions_hash)?,
@@ -133,11 +137,13 @@ pub(crate) struct BlockInfoDto {
 }
 
 impl BlockInfoDto {
-    pub fn compact(self) -> Result<BlockInfo> {
+    pub fn compact(self, epoch: i64) -> Result<BlockInfo> {
         self.block.compact(
+            self.meta.hash,
             self.meta.generation_hash,
             self.meta.num_transactions,
             self.meta.total_fee.0,
+            epoch,
         )
     }
 }
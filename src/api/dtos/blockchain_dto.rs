@@ -4,7 +4,10 @@
  * license that can be found in the LICENSE file.
  */
 
-use crate::blockchain::{BlockchainScore, HeightInfo};
+use crate::{
+    blockchain::{BlockchainScore, HeightInfo},
+    network::NetworkConfig,
+};
 
 use super::{AbstractTransactionDto, Uint64Dto, UpgradeDto};
 
@@ -55,3 +58,28 @@ pub(crate) struct BlockchainUpgradeTransactionDto {
     #[serde(rename = "newBlockChainVersion")]
     new_block_chain_version: Uint64Dto,
 }
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NetworkConfigDto {
+    network_config: NetworkConfigInfoDto,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NetworkConfigInfoDto {
+    height: Uint64Dto,
+    network_config: String,
+    #[serde(default)]
+    supported_entity_versions: String,
+}
+
+impl NetworkConfigDto {
+    pub fn compact(self) -> NetworkConfig {
+        NetworkConfig {
+            height: self.network_config.height.compact(),
+            network_config: self.network_config.network_config,
+            supported_entity_versions: self.network_config.supported_entity_versions,
+        }
+    }
+}
@@ -184,11 +184,11 @@ pub(crate) struct AddExchangeOfferTransactionInfoDto {
 
 #[typetag::serde]
 impl TransactionDto for AddExchangeOfferTransactionInfoDto {
-    fn compact(&self) -> Result<Box<dyn Transaction>> {
+    fn compact(&self, epoch: i64) -> Result<Box<dyn Transaction>> {
         let dto = self.transaction.clone();
         let info = self.meta.compact()?;
 
-        let abs_transaction = dto.r#abstract.compact(info)?;
+        let abs_transaction = dto.r#abstract.compact(info, epoch)?;
 
         let offers: Vec<AddOffer> = dto
             .offers
@@ -227,11 +227,11 @@ pub(crate) struct ExchangeOfferTransactionInfoDto {
 
 #[typetag::serde]
 impl TransactionDto for ExchangeOfferTransactionInfoDto {
-    fn compact(&self) -> Result<Box<dyn Transaction>> {
+    fn compact(&self, epoch: i64) -> Result<Box<dyn Transaction>> {
         let dto = self.transaction.clone();
         let info = self.meta.compact()?;
 
-        let abs_transaction = dto.r#abstract.compact(info)?;
+        let abs_transaction = dto.r#abstract.compact(info, epoch)?;
 
         let confirmations: Vec<ExchangeConfirmation> = dto
             .offers
@@ -274,11 +274,11 @@ pub(crate) struct RemoveExchangeOfferTransactionInfoDto {
 
 #[typetag::serde]
 impl TransactionDto for RemoveExchangeOfferTransactionInfoDto {
-    fn compact(&self) -> Result<Box<dyn Transaction>> {
+    fn compact(&self, epoch: i64) -> Result<Box<dyn Transaction>> {
         let dto = self.transaction.clone();
         let info = self.meta.compact()?;
 
-        let abs_transaction = dto.r#abstract.compact(info)?;
+        let abs_transaction = dto.r#abstract.compact(info, epoch)?;
 
         let offers: Vec<RemoveOffer> = dto
             .offers
@@ -5,8 +5,13 @@
  */
 
 use crate::{
-    helpers::hex_decode,
-    message::{Message, PlainMessage},
+    errors_const,
+    helpers::is_hex,
+    message::{
+        Message, MessageType, PersistentHarvestingDelegationMessage, PlainMessage, RawMessage,
+        SecureMessage, UnknownMessage,
+    },
+    Result,
 };
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -18,18 +23,28 @@ pub(crate) struct MessageDto {
 }
 
 impl MessageDto {
-    pub fn compact(&self) -> Box<dyn Message> {
-        if self._type == 0 {
-            let plain = if !self.payload.is_empty() {
-                let b = hex_decode(&self.payload);
-                PlainMessage::new(&String::from_utf8(b).unwrap())
-            } else {
-                PlainMessage::default()
-            };
+    pub fn compact(&self) -> Result<Box<dyn Message>> {
+        let payload = hex::decode(&self.payload)
+            .map_err(|_| format_err!("{}", errors_const::ERR_INVALID_PAYLOAD_HEX))?;
 
-            Box::new(plain)
-        } else {
-            unimplemented!()
-        }
+        let message: Box<dyn Message> = match MessageType::from(self._type) {
+            MessageType::PlainMessageType => match String::from_utf8(payload) {
+                Ok(text) => Box::new(PlainMessage::new(&text)),
+                Err(err) => Box::new(RawMessage::new(err.as_bytes())),
+            },
+            MessageType::SecureMessageType => match String::from_utf8(payload.clone()) {
+                Ok(ref text) if is_hex(text) => Box::new(SecureMessage::from_hex_payload(text)?),
+                _ => Box::new(UnknownMessage::new(self._type, &payload)),
+            },
+            MessageType::PersistentHarvestingDelegationMessageType => {
+                match PersistentHarvestingDelegationMessage::from_bytes(&payload) {
+                    Ok(message) => Box::new(message),
+                    Err(_) => Box::new(UnknownMessage::new(self._type, &payload)),
+                }
+            }
+            MessageType::UnknownMessageType => Box::new(UnknownMessage::new(self._type, &payload)),
+        };
+
+        Ok(message)
     }
 }
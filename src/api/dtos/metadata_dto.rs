@@ -79,8 +79,12 @@ pub(crate) struct ModifyMetadataTransactionDto {
 }
 
 impl ModifyMetadataTransactionDto {
-    pub fn compact(&self, info: TransactionInfo) -> crate::Result<ModifyMetadataTransaction> {
-        let abs_transaction = self.r#abstract.compact(info)?;
+    pub fn compact(
+        &self,
+        info: TransactionInfo,
+        epoch: i64,
+    ) -> crate::Result<ModifyMetadataTransaction> {
+        let abs_transaction = self.r#abstract.compact(info, epoch)?;
 
         let modifications = metadata_dto_vec_to_struct(self.modifications.clone());
         Ok(ModifyMetadataTransaction {
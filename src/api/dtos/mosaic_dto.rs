@@ -95,12 +95,12 @@ pub(crate) struct MosaicDefinitionTransactionDto {
 
 #[typetag::serde]
 impl TransactionDto for MosaicDefinitionTransactionInfoDto {
-    fn compact(&self) -> Result<Box<dyn Transaction>> {
+    fn compact(&self, epoch: i64) -> Result<Box<dyn Transaction>> {
         let dto = self.transaction.clone();
 
         let info = self.meta.compact()?;
 
-        let abs_transaction = dto.r#abstract.compact(info)?;
+        let abs_transaction = dto.r#abstract.compact(info, epoch)?;
 
         let properties = mosaic_properties(&dto.properties)?;
 
@@ -144,12 +144,12 @@ pub(crate) struct MosaicMetadataTransactionInfoDto {
 
 #[typetag::serde]
 impl TransactionDto for MosaicMetadataTransactionInfoDto {
-    fn compact(&self) -> Result<Box<dyn Transaction>> {
+    fn compact(&self, epoch: i64) -> Result<Box<dyn Transaction>> {
         let dto = self.transaction.clone();
 
         let info = self.meta.compact()?;
 
-        let metadata_transaction = dto.metadata_transaction.compact(info)?;
+        let metadata_transaction = dto.metadata_transaction.compact(info, epoch)?;
 
         let mosaic_id = MosaicId::from(dto.mosaic_id.compact());
 
@@ -191,12 +191,12 @@ pub(crate) struct MosaicSupplyChangeTransactionInfoDto {
 
 #[typetag::serde]
 impl TransactionDto for MosaicSupplyChangeTransactionInfoDto {
-    fn compact(&self) -> Result<Box<dyn Transaction>> {
+    fn compact(&self, epoch: i64) -> Result<Box<dyn Transaction>> {
         let dto = self.transaction.clone();
 
         let info = self.meta.compact()?;
 
-        let abs_transaction = dto.r#abstract.compact(info)?;
+        let abs_transaction = dto.r#abstract.compact(info, epoch)?;
 
         Ok(Box::new(MosaicSupplyChangeTransaction {
             abs_transaction,
@@ -102,11 +102,11 @@ pub(crate) struct ModifyMultisigAccountTransactionDto {
 
 #[typetag::serde]
 impl TransactionDto for ModifyMultisigAccountTransactionInfoDto {
-    fn compact(&self) -> Result<Box<dyn Transaction>> {
+    fn compact(&self, epoch: i64) -> Result<Box<dyn Transaction>> {
         let dto = self.transaction.clone();
         let info = self.meta.compact()?;
 
-        let abs_transaction = dto.r#abstract.compact(info)?;
+        let abs_transaction = dto.r#abstract.compact(info, epoch)?;
 
         let modifications =
             cosignatory_dto_vec_to_struct(dto.modifications, abs_transaction.network_type);
@@ -146,12 +146,12 @@ pub(crate) struct NamespaceMetadataTransactionInfoDto {
 
 #[typetag::serde]
 impl TransactionDto for NamespaceMetadataTransactionInfoDto {
-    fn compact(&self) -> Result<Box<dyn Transaction>> {
+    fn compact(&self, epoch: i64) -> Result<Box<dyn Transaction>> {
         let dto = self.transaction.clone();
 
         let info = self.meta.compact()?;
 
-        let metadata_transaction = dto.metadata_transaction.compact(info)?;
+        let metadata_transaction = dto.metadata_transaction.compact(info, epoch)?;
 
         let namespace_id = NamespaceId::from(dto.namespace_id.compact());
 
@@ -206,12 +206,12 @@ pub(crate) struct RegisterNamespaceTransactionInfoDto {
 
 #[typetag::serde]
 impl TransactionDto for RegisterNamespaceTransactionInfoDto {
-    fn compact(&self) -> Result<Box<dyn Transaction>> {
+    fn compact(&self, epoch: i64) -> Result<Box<dyn Transaction>> {
         let dto = self.transaction.clone();
 
         let info = self.meta.compact()?;
 
-        let abs_transaction = dto.r#abstract.compact(info)?;
+        let abs_transaction = dto.r#abstract.compact(info, epoch)?;
 
         let namespace_type = NamespaceType::from(dto.namespace_type);
 
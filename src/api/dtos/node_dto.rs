@@ -4,10 +4,39 @@
  * license that can be found in the LICENSE file.
  */
 
-use crate::{node::NodeTime, Uint64};
+use crate::{
+    node::{NodeInfo, NodeTime},
+    RolesType, Uint64,
+};
 
 use super::Uint64Dto;
 
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NodeInfoDto {
+    public_key: String,
+    port: u16,
+    network_identifier: u8,
+    version: u16,
+    roles: u8,
+    host: String,
+    friendly_name: String,
+}
+
+impl NodeInfoDto {
+    pub(crate) fn compact(self) -> NodeInfo {
+        NodeInfo {
+            public_key: self.public_key,
+            port: self.port,
+            network_identifier: self.network_identifier,
+            version: self.version,
+            roles: RolesType::from_bits(self.roles),
+            host: self.host,
+            friendly_name: self.friendly_name,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub(crate) struct NodeTimeDto {
     #[serde(rename = "communicationTimestamps")]
@@ -4,21 +4,73 @@
  * license that can be found in the LICENSE file.
  */
 
+use crate::{
+    account::Address,
+    models::Result,
+    mosaic::MosaicId,
+    receipt::{
+        AddressResolutionStatement, MosaicResolutionStatement, ResolutionEntry, ResolutionStatement,
+    },
+};
+
 use super::{SourceDto, Uint64Dto};
 
 #[derive(Serialize, Deserialize)]
-pub(crate) struct ResolutionEntryDto {
+pub(crate) struct ResolutionEntryDto<T> {
     #[serde(rename = "source")]
     source: SourceDto,
     #[serde(rename = "resolved")]
-    resolved: Uint64Dto,
+    resolved: T,
 }
 
 /// ResolutionStatementDto : A resolution statement keeps the relation between a namespace alias used in a transaction and the real address or mosaic_id.
 #[derive(Serialize, Deserialize)]
-pub(crate) struct ResolutionStatementDto {
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ResolutionStatementDto<T> {
     height: Uint64Dto,
-    unresolved: Uint64Dto,
+    unresolved: T,
     /// The array of resolution entries linked to the unresolved namespace_id. It is an array instead of a single UInt64 field since within one block the resolution might change for different sources due to alias related transactions.
-    resolution_entries: Vec<ResolutionEntryDto>,
+    resolution_entries: Vec<ResolutionEntryDto<T>>,
+}
+
+/// The unresolved and resolved addresses are encoded as hexadecimal.
+pub(crate) type AddressResolutionStatementDto = ResolutionStatementDto<String>;
+
+pub(crate) type MosaicResolutionStatementDto = ResolutionStatementDto<Uint64Dto>;
+
+impl AddressResolutionStatementDto {
+    pub fn compact(&self) -> Result<AddressResolutionStatement> {
+        let mut resolution_entries = vec![];
+        for entry in self.resolution_entries.iter() {
+            resolution_entries.push(ResolutionEntry {
+                source: entry.source.compact(),
+                resolved: Address::from_encoded(&entry.resolved)?,
+            });
+        }
+
+        Ok(ResolutionStatement {
+            height: self.height.compact(),
+            unresolved: Address::from_encoded(&self.unresolved)?,
+            resolution_entries,
+        })
+    }
+}
+
+impl MosaicResolutionStatementDto {
+    pub fn compact(&self) -> MosaicResolutionStatement {
+        let resolution_entries = self
+            .resolution_entries
+            .iter()
+            .map(|entry| ResolutionEntry {
+                source: entry.source.compact(),
+                resolved: MosaicId::from(entry.resolved.compact()),
+            })
+            .collect();
+
+        ResolutionStatement {
+            height: self.height.compact(),
+            unresolved: *self.unresolved.compact(),
+            resolution_entries,
+        }
+    }
 }
@@ -4,24 +4,148 @@
  * license that can be found in the LICENSE file.
  */
 
-use super::{ResolutionStatementDto, SourceDto};
+use serde_json::Value;
+
+use crate::{
+    account::Address,
+    models::Result,
+    mosaic::MosaicId,
+    network::NetworkType,
+    receipt::{
+        BalanceReceipt, BlockResolutions, ReceiptSource, RECEIPT_BASIC_TYPE_BALANCE_CREDIT,
+        RECEIPT_BASIC_TYPE_BALANCE_DEBIT, RECEIPT_BASIC_TYPE_BALANCE_TRANSFER,
+    },
+    transaction::Height,
+};
+
+use super::{AddressResolutionStatementDto, MosaicResolutionStatementDto, SourceDto, Uint64Dto};
 
 /// StatementsDto : The collection of transaction statements and resolutions triggered for the block requested.
 #[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) struct StatementsDto {
     /// The array of transaction statements for the block requested.
     transaction_statements: Vec<TransactionStatementDto>,
     /// The array of address resolutions for the block requested.
-    address_resolution_statements: Vec<ResolutionStatementDto>,
+    address_resolution_statements: Vec<AddressResolutionStatementDto>,
     /// The array of mosaic resolutions for the block requested.
-    mosaic_resolution_statements: Vec<ResolutionStatementDto>,
+    mosaic_resolution_statements: Vec<MosaicResolutionStatementDto>,
+}
+
+impl StatementsDto {
+    pub fn compact_resolutions(&self, height: Height) -> Result<BlockResolutions> {
+        let mut address_resolution_statements = vec![];
+        for statement in self.address_resolution_statements.iter() {
+            address_resolution_statements.push(statement.compact()?);
+        }
+
+        let mosaic_resolution_statements = self
+            .mosaic_resolution_statements
+            .iter()
+            .map(|statement| statement.compact())
+            .collect();
+
+        Ok(BlockResolutions {
+            height,
+            address_resolution_statements,
+            mosaic_resolution_statements,
+        })
+    }
+
+    /// The receipts of the block that changed the balance of an account, in order of
+    /// source. Any other receipt is skipped.
+    pub fn compact_balance_receipts(
+        &self,
+        height: Height,
+        network_type: NetworkType,
+    ) -> Result<Vec<BalanceReceipt>> {
+        let mut receipts = vec![];
+        for statement in self.transaction_statements.iter() {
+            let source = statement.source.compact();
+
+            for value in statement.receipts.iter() {
+                let dto: BalanceReceiptDto = match serde_json::from_value(value.clone()) {
+                    Ok(dto) => dto,
+                    Err(_) => continue,
+                };
+
+                if let Some(receipt) = dto.compact(height, source, network_type)? {
+                    receipts.push(receipt);
+                }
+            }
+        }
+        receipts.sort_by_key(|receipt| receipt.source);
+        Ok(receipts)
+    }
+}
+
+/// BalanceReceiptDto : A balance change or balance transfer receipt.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BalanceReceiptDto {
+    #[serde(rename = "type")]
+    _type: u16,
+    /// The public key of the account credited or debited.
+    #[serde(alias = "targetPublicKey", skip_serializing_if = "Option::is_none")]
+    account: Option<String>,
+    /// The public key of the account sending the mosaics of a transfer.
+    #[serde(alias = "senderPublicKey", skip_serializing_if = "Option::is_none")]
+    sender: Option<String>,
+    /// The encoded address of the account receiving the mosaics of a transfer.
+    #[serde(alias = "recipientAddress", skip_serializing_if = "Option::is_none")]
+    recipient: Option<String>,
+    mosaic_id: Uint64Dto,
+    amount: Uint64Dto,
+}
+
+impl BalanceReceiptDto {
+    fn compact(
+        &self,
+        height: Height,
+        source: ReceiptSource,
+        network_type: NetworkType,
+    ) -> Result<Option<BalanceReceipt>> {
+        let from_public_key = |public_key: &Option<String>| -> Result<Option<Address>> {
+            match public_key {
+                Some(public_key) => Ok(Some(Address::from_public_key(public_key, network_type)?)),
+                None => Ok(None),
+            }
+        };
+
+        let (from, to) = match self._type >> 12 {
+            RECEIPT_BASIC_TYPE_BALANCE_TRANSFER => {
+                let to = match self.recipient {
+                    Some(ref recipient) => Some(Address::from_encoded(recipient)?),
+                    None => None,
+                };
+                (from_public_key(&self.sender)?, to)
+            }
+            RECEIPT_BASIC_TYPE_BALANCE_CREDIT => (None, from_public_key(&self.account)?),
+            RECEIPT_BASIC_TYPE_BALANCE_DEBIT => (from_public_key(&self.account)?, None),
+            _ => return Ok(None),
+        };
+
+        if from.is_none() && to.is_none() {
+            return Ok(None);
+        }
+
+        Ok(Some(BalanceReceipt {
+            height,
+            source,
+            receipt_type: self._type,
+            from,
+            to,
+            mosaic_id: MosaicId::from(*self.mosaic_id.compact()),
+            amount: self.amount.compact(),
+        }))
+    }
 }
 
 /// TransactionStatementDto : The collection of receipts related to a transaction.
 #[derive(Serialize, Deserialize)]
 pub(crate) struct TransactionStatementDto {
-    height: Vec<i32>,
+    height: Uint64Dto,
     source: SourceDto,
     /// The array of receipts.
-    receipts: Vec<String>,
+    receipts: Vec<Value>,
 }
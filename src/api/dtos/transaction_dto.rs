@@ -40,7 +40,7 @@ pub(crate) enum HashAlgorithm {
 
 #[typetag::serde]
 pub(crate) trait TransactionDto {
-    fn compact(&self) -> Result<Box<dyn Transaction>>;
+    fn compact(&self, epoch: i64) -> Result<Box<dyn Transaction>>;
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -65,7 +65,7 @@ pub(crate) struct AbstractTransactionDto {
 }
 
 impl AbstractTransactionDto {
-    pub fn compact(&self, info: TransactionInfo) -> crate::Result<AbstractTransaction> {
+    pub fn compact(&self, info: TransactionInfo, epoch: i64) -> crate::Result<AbstractTransaction> {
         let dto = self;
 
         let network_type = extract_network_type(self.version as u32);
@@ -76,7 +76,9 @@ impl AbstractTransactionDto {
 
         let deadline = if let Some(item) = &dto.deadline {
             let timestamp = BlockchainTimestamp::new(*item.compact() as i64);
-            Some(Deadline::from(timestamp))
+            Some(Deadline::from_blockchain_timestamp_with_epoch(
+                timestamp, epoch,
+            ))
         } else {
             None
         };
@@ -206,12 +208,15 @@ pub(crate) struct TransactionStatusDto {
 }
 
 impl TransactionStatusDto {
-    pub fn compact(&self) -> crate::Result<TransactionStatus> {
+    pub fn compact(&self, epoch: i64) -> crate::Result<TransactionStatus> {
         let dto = &self.to_owned();
 
         let deadline = if let Some(value) = &dto.deadline {
             let blockchain_timestamp = BlockchainTimestamp::new(*value.compact() as i64);
-            Some(Deadline::from(blockchain_timestamp))
+            Some(Deadline::from_blockchain_timestamp_with_epoch(
+                blockchain_timestamp,
+                epoch,
+            ))
         } else {
             None
         };
@@ -241,11 +246,11 @@ pub(crate) struct TransferTransactionInfoDto {
 
 #[typetag::serde]
 impl TransactionDto for TransferTransactionInfoDto {
-    fn compact(&self) -> Result<Box<dyn Transaction>> {
+    fn compact(&self, epoch: i64) -> Result<Box<dyn Transaction>> {
         let dto = self.transaction.clone();
         let info = self.meta.compact()?;
 
-        let abs_transaction = dto.r#abstract.compact(info)?;
+        let abs_transaction = dto.r#abstract.compact(info, epoch)?;
 
         let mut mosaics: Vec<Mosaic> = vec![];
         if let Some(value) = &dto.mosaics {
@@ -260,7 +265,7 @@ impl TransactionDto for TransferTransactionInfoDto {
             abs_transaction,
             recipient,
             mosaics,
-            message: dto.message.compact(),
+            message: dto.message.compact()?,
         }))
     }
 }
@@ -299,11 +304,11 @@ pub(crate) struct HashLockTransactionInfoDto {
 
 #[typetag::serde]
 impl TransactionDto for HashLockTransactionInfoDto {
-    fn compact(&self) -> Result<Box<dyn Transaction>> {
+    fn compact(&self, epoch: i64) -> Result<Box<dyn Transaction>> {
         let dto = self.transaction.clone();
         let info = self.meta.compact()?;
 
-        let abs_transaction = dto.r#abstract.compact(info)?;
+        let abs_transaction = dto.r#abstract.compact(info, epoch)?;
 
         let mosaic = Mosaic::new(
             MosaicId::from(dto.mosaic_id.compact()),
@@ -27,8 +27,8 @@ use super::{
     CosignatoryModificationDto, MetadataModificationDto, MosaicPropertyDto, TransactionDto,
 };
 
-const TRANSACTION_ORDER_ASC: &str = "id";
-const TRANSACTION_ORDER_DESC: &str = "-id";
+pub(crate) const TRANSACTION_ORDER_ASC: &str = "id";
+pub(crate) const TRANSACTION_ORDER_DESC: &str = "-id";
 
 pub(crate) struct AccountTransactionsOption {
     pub page_size: Option<i32>,
@@ -4,12 +4,18 @@
  * license that can be found in the LICENSE file.
  */
 
+pub use self::alias_cache::*;
 pub(crate) use self::dtos::*;
 pub(crate) use self::internally::*;
+pub use self::node_discovery::*;
+pub use self::pagination::*;
 pub use self::sirius_client::*;
 
+mod alias_cache;
 mod dtos;
 mod internally;
+mod node_discovery;
+mod pagination;
 mod request;
-mod routes;
+pub(crate) mod routes;
 mod sirius_client;
@@ -0,0 +1,246 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use {
+    ::std::{
+        collections::HashSet,
+        sync::Arc,
+        time::{Duration, Instant},
+    },
+    futures_util::stream::{self, StreamExt},
+    reqwest::Client as ReqwestClient,
+};
+
+use crate::{
+    errors_const::{ERR_EMPTY_NODE_URLS, ERR_INVALID_CONCURRENCY, ERR_NO_HEALTHY_NODES},
+    models::{error::Error, Result},
+    node::NodeInfo,
+    transaction::HashValue,
+};
+
+use super::{
+    routes::{
+        block_routes_api::BlockRoutes, chain_routes_api::ChainRoutes, node_routes_api::NodeRoutes,
+    },
+    sirius_client::ApiClient,
+};
+
+/// The default port the REST api of discovered peers is expected on.
+pub const DISCOVERY_API_PORT: u16 = 3000;
+/// The default maximum number of nodes probed by a discovery.
+pub const DISCOVERY_MAX_NODES: usize = 32;
+/// The default number of nodes probed at the same time.
+pub const DISCOVERY_CONCURRENCY: usize = 8;
+/// The default number of blocks a node may be behind the highest node and still be healthy.
+pub const DISCOVERY_MAX_HEIGHT_LAG: u64 = 5;
+/// The default time after which a request to a probed node is abandoned.
+pub const DISCOVERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Options of a node discovery.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscoveryOptions {
+    /// The generation hash of the network. When not set, the one of the first seed which
+    /// answers is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generation_hash: Option<HashValue>,
+    /// The scheme of the urls built for peers.
+    pub scheme: String,
+    /// The port of the urls built for peers, as peers only report their p2p port.
+    pub api_port: u16,
+    pub max_nodes: usize,
+    pub concurrency: usize,
+    pub max_height_lag: u64,
+    pub timeout: Duration,
+}
+
+impl DiscoveryOptions {
+    pub fn new(generation_hash: HashValue) -> Self {
+        DiscoveryOptions {
+            generation_hash: Some(generation_hash),
+            ..Default::default()
+        }
+    }
+
+    fn peer_url(&self, peer: &NodeInfo) -> String {
+        format!("{}://{}:{}", self.scheme, peer.host, self.api_port)
+    }
+}
+
+impl Default for DiscoveryOptions {
+    fn default() -> Self {
+        DiscoveryOptions {
+            generation_hash: None,
+            scheme: "http".to_string(),
+            api_port: DISCOVERY_API_PORT,
+            max_nodes: DISCOVERY_MAX_NODES,
+            concurrency: DISCOVERY_CONCURRENCY,
+            max_height_lag: DISCOVERY_MAX_HEIGHT_LAG,
+            timeout: DISCOVERY_TIMEOUT,
+        }
+    }
+}
+
+impl core::fmt::Display for DiscoveryOptions {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+/// An api node found by [discover_nodes].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscoveredNode {
+    /// The url the REST api of the node answered on.
+    pub url: String,
+    pub info: NodeInfo,
+    /// The chain height of the node when it was probed.
+    pub height: u64,
+    pub generation_hash: HashValue,
+    /// The time the node took to answer its info request.
+    pub latency: Duration,
+}
+
+impl core::fmt::Display for DiscoveredNode {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+/// Returns the urls of [nodes], in order, for `SiriusClient::new_from_urls`.
+pub fn discovered_urls(nodes: &[DiscoveredNode]) -> Vec<String> {
+    nodes.iter().map(|node| node.url.clone()).collect()
+}
+
+async fn probe_node(client: ReqwestClient, url: String) -> Result<(DiscoveredNode, Vec<NodeInfo>)> {
+    let api_client = Arc::new(ApiClient::new_with_client(client, url.clone()));
+    let node_routes = NodeRoutes::new(Arc::clone(&api_client));
+
+    let start = Instant::now();
+    let info = node_routes.clone().get_node_info().await?;
+    let latency = start.elapsed();
+
+    let height = ChainRoutes::new(Arc::clone(&api_client))
+        .get_blockchain_height()
+        .await?;
+    let nemesis = BlockRoutes::new(api_client).get_block_by_height(1).await?;
+
+    // Not every node exposes its peers, which only stops the crawl from going further.
+    let peers = node_routes.get_node_peers().await.unwrap_or_default();
+
+    let node = DiscoveredNode {
+        url,
+        info,
+        height: *height.height,
+        generation_hash: nemesis.generation_hash,
+        latency,
+    };
+
+    Ok((node, peers))
+}
+
+fn normalize_url(url: &str) -> String {
+    url.trim().trim_end_matches('/').to_string()
+}
+
+/// Discovers the healthy api nodes of a network.
+///
+/// Starting from [seeds], probes the info, chain height and latency of each node, and
+/// crawls the api nodes among their peers until [DiscoveryOptions::max_nodes] nodes were
+/// probed. Nodes which do not answer, are on another generation hash, or are more than
+/// [DiscoveryOptions::max_height_lag] blocks behind the highest node are left out.
+///
+/// # Inputs
+///
+/// * `seeds` =    The urls of the nodes the discovery starts from.
+///
+/// * `options` =    The [DiscoveryOptions] of the discovery.
+///
+/// # Returns
+///
+/// Returns a Future `Result` whose okay value is the healthy nodes, fastest first, or
+/// whose error value is an `Error<Value>` describing the error that occurred.
+pub async fn discover_nodes<T: AsRef<str>>(
+    seeds: &[T],
+    options: DiscoveryOptions,
+) -> Result<Vec<DiscoveredNode>> {
+    if seeds.is_empty() {
+        return Err(Error::from(ERR_EMPTY_NODE_URLS));
+    }
+    if options.concurrency == 0 {
+        return Err(Error::from(ERR_INVALID_CONCURRENCY));
+    }
+
+    let client = ReqwestClient::builder().timeout(options.timeout).build()?;
+
+    let mut visited: HashSet<String> = HashSet::new();
+    let mut queue: Vec<String> = vec![];
+    for seed in seeds.iter() {
+        let url = normalize_url(seed.as_ref());
+        if visited.insert(url.clone()) {
+            queue.push(url);
+        }
+    }
+
+    let mut generation_hash = options.generation_hash;
+    let mut probed = 0;
+    let mut nodes: Vec<DiscoveredNode> = vec![];
+
+    while !queue.is_empty() && probed < options.max_nodes {
+        let batch_size = queue.len().min(options.max_nodes - probed);
+        let batch: Vec<String> = queue.drain(..batch_size).collect();
+        probed += batch.len();
+
+        let results: Vec<Result<(DiscoveredNode, Vec<NodeInfo>)>> = stream::iter(batch)
+            .map(|url| probe_node(client.clone(), url))
+            .buffered(options.concurrency)
+            .collect()
+            .await;
+
+        for (node, peers) in results.into_iter().filter_map(|result| result.ok()) {
+            let expected = *generation_hash.get_or_insert(node.generation_hash);
+            if node.generation_hash != expected {
+                continue;
+            }
+
+            for peer in peers.iter().filter(|peer| peer.is_api_node()) {
+                let url = options.peer_url(peer);
+                if visited.insert(url.clone()) {
+                    queue.push(url);
+                }
+            }
+
+            nodes.push(node);
+        }
+    }
+
+    let max_height = nodes
+        .iter()
+        .map(|node| node.height)
+        .max()
+        .unwrap_or_default();
+    nodes.retain(|node| node.height.saturating_add(options.max_height_lag) >= max_height);
+
+    if nodes.is_empty() {
+        return Err(Error::from(ERR_NO_HEALTHY_NODES));
+    }
+
+    nodes.sort_by(|a, b| {
+        a.latency
+            .cmp(&b.latency)
+            .then_with(|| b.height.cmp(&a.height))
+    });
+
+    Ok(nodes)
+}
@@ -0,0 +1,197 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use {
+    ::std::{collections::VecDeque, future::Future},
+    futures_util::stream::{self, Stream},
+};
+
+use crate::{
+    models::Result,
+    transaction::{HashValue, Height, Transaction, Transactions},
+};
+
+use super::{TRANSACTION_ORDER_ASC, TRANSACTION_ORDER_DESC};
+
+/// The default number of transactions requested per page when streaming history.
+pub const HISTORY_PAGE_SIZE: i32 = 100;
+/// The smallest page size accepted by the nodes.
+pub const HISTORY_PAGE_SIZE_MIN: i32 = 10;
+/// The largest page size accepted by the nodes.
+pub const HISTORY_PAGE_SIZE_MAX: i32 = 100;
+
+/// The order transactions are streamed in.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TransactionOrdering {
+    /// Oldest transactions first.
+    Ascending,
+    /// Newest transactions first.
+    Descending,
+}
+
+impl TransactionOrdering {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            TransactionOrdering::Ascending => TRANSACTION_ORDER_ASC,
+            TransactionOrdering::Descending => TRANSACTION_ORDER_DESC,
+        }
+    }
+}
+
+/// Where a transaction history stream ends before the history is exhausted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum HistoryStop {
+    /// Stops at the first transaction past this height, in the stream ordering.
+    Height(Height),
+    /// Stops at the transaction with this hash, which is not yielded.
+    Hash(HashValue),
+}
+
+impl HistoryStop {
+    fn is_reached(&self, transaction: &dyn Transaction, ordering: TransactionOrdering) -> bool {
+        if transaction.abs_transaction().transaction_info.is_none() {
+            return false;
+        }
+
+        match self {
+            HistoryStop::Height(height) => match ordering {
+                TransactionOrdering::Ascending => *transaction.height() > **height,
+                TransactionOrdering::Descending => *transaction.height() < **height,
+            },
+            HistoryStop::Hash(hash) => transaction.transaction_hash() == *hash,
+        }
+    }
+}
+
+/// Options of a transaction history stream.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionHistoryOptions {
+    /// The number of transactions requested per page, clamped to the range accepted by
+    /// the nodes when requested.
+    pub page_size: i32,
+    pub ordering: TransactionOrdering,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<HistoryStop>,
+}
+
+impl TransactionHistoryOptions {
+    pub fn new(ordering: TransactionOrdering) -> Self {
+        TransactionHistoryOptions {
+            ordering,
+            ..Default::default()
+        }
+    }
+
+    /// Requests [page_size] transactions per page, clamped between
+    /// `HISTORY_PAGE_SIZE_MIN` and `HISTORY_PAGE_SIZE_MAX`.
+    pub fn with_page_size(mut self, page_size: i32) -> Self {
+        self.page_size = page_size.clamp(HISTORY_PAGE_SIZE_MIN, HISTORY_PAGE_SIZE_MAX);
+        self
+    }
+
+    /// The page size requested from the node, within the range it accepts.
+    pub fn page_size(&self) -> i32 {
+        self.page_size
+            .clamp(HISTORY_PAGE_SIZE_MIN, HISTORY_PAGE_SIZE_MAX)
+    }
+
+    /// Stops the stream at the first transaction past [height].
+    pub fn stop_at_height(mut self, height: Height) -> Self {
+        self.stop = Some(HistoryStop::Height(height));
+        self
+    }
+
+    /// Stops the stream at the transaction with [hash], which is not yielded.
+    pub fn stop_at_hash(mut self, hash: HashValue) -> Self {
+        self.stop = Some(HistoryStop::Hash(hash));
+        self
+    }
+}
+
+impl Default for TransactionHistoryOptions {
+    fn default() -> Self {
+        TransactionHistoryOptions {
+            page_size: HISTORY_PAGE_SIZE,
+            ordering: TransactionOrdering::Descending,
+            stop: None,
+        }
+    }
+}
+
+impl core::fmt::Display for TransactionHistoryOptions {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+struct HistoryState<F> {
+    fetch: F,
+    options: TransactionHistoryOptions,
+    cursor: Option<String>,
+    page: VecDeque<Box<dyn Transaction>>,
+    done: bool,
+}
+
+/// Streams every transaction returned by [fetch], following the id cursor from page to
+/// page until a page comes back empty or the stop condition of [options] is reached.
+///
+/// [fetch] is called with the id of the last transaction of the previous page. A short
+/// page does not end the stream, as a node may serve fewer transactions than requested.
+pub(crate) fn paginate_transactions<F, Fut>(
+    options: TransactionHistoryOptions,
+    fetch: F,
+) -> impl Stream<Item = Result<Box<dyn Transaction>>>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<Transactions>>,
+{
+    let state = HistoryState {
+        fetch,
+        options,
+        cursor: None,
+        page: VecDeque::new(),
+        done: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(transaction) = state.page.pop_front() {
+                if let Some(ref stop) = state.options.stop {
+                    if stop.is_reached(transaction.as_ref(), state.options.ordering) {
+                        return None;
+                    }
+                }
+                return Some((Ok(transaction), state));
+            }
+
+            if state.done {
+                return None;
+            }
+
+            match (state.fetch)(state.cursor.take()).await {
+                Ok(transactions) => {
+                    state.cursor = transactions
+                        .last()
+                        .and_then(|transaction| transaction.abs_transaction().transaction_info)
+                        .map(|info| info.id);
+
+                    state.done = state.cursor.is_none();
+
+                    state.page.extend(transactions);
+                }
+                Err(err) => {
+                    state.done = true;
+                    return Some((Err(err), state));
+                }
+            }
+        }
+    })
+}
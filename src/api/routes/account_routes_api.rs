@@ -6,20 +6,26 @@
 
 use {
     ::std::{collections::HashMap, future::Future, sync::Arc},
+    futures_util::stream::Stream,
     reqwest::Method,
 };
 
 use crate::{
-    account::{AccountInfo, AccountName, AccountProperties, AccountsId, Address, PublicAccount},
+    account::{
+        AccountInfo, AccountName, AccountProperties, AccountPropertiesConfig, AccountsId, Address,
+        PublicAccount,
+    },
     api::{
         internally::{str_to_account_id, valid_vec_len, AccountTransactionsOption},
-        request as __internal_request, AccountInfoDto, AccountNamesDto, AccountPropertiesInfoDto,
-        ApiClient, MultisigAccountGraphInfoDto, MultisigAccountInfoDto, TransactionDto,
+        paginate_transactions, request as __internal_request, AccountInfoDto, AccountNamesDto,
+        AccountPropertiesInfoDto, ApiClient, MultisigAccountGraphInfoDto, MultisigAccountInfoDto,
+        TransactionDto, TransactionHistoryOptions,
     },
     errors_const::ERR_EMPTY_ADDRESSES_IDS,
     models::Result,
     multisig::{MultisigAccountGraphInfo, MultisigAccountInfo},
-    transaction::Transactions,
+    network::NetworkType,
+    transaction::{Deadline, Transaction, Transactions},
 };
 
 use super::{
@@ -213,6 +219,35 @@ impl AccountRoutes {
         dto?.compact()
     }
 
+    /// Creates the minimal account property transactions that turn the current
+    /// properties of the account into [desired], in the order they must be announced.
+    ///
+    /// Fails for configurations the chain would reject, such as allowing and blocking
+    /// the same kind of property.
+    pub async fn create_account_properties_update(
+        self,
+        address: Address,
+        desired: &AccountPropertiesConfig,
+        deadline: Deadline,
+        network_type: NetworkType,
+    ) -> Result<Vec<Box<dyn Transaction>>> {
+        let current = match self.account_properties(address).await {
+            Ok(properties) => properties,
+            Err(err) if err.is_not_found() => AccountProperties {
+                address,
+                allowed_addresses: vec![],
+                allowed_mosaic_id: vec![],
+                allowed_entity_types: vec![],
+                blocked_addresses: vec![],
+                blocked_mosaic_id: vec![],
+                blocked_entity_types: vec![],
+            },
+            Err(err) => return Err(err),
+        };
+
+        Ok(current.diff(desired, deadline, network_type)?)
+    }
+
     pub async fn accounts_properties(
         self,
         accounts_id: Vec<&str>,
@@ -321,6 +356,59 @@ impl AccountRoutes {
         .await
     }
 
+    /// Streams the confirmed transactions of [public_account], fetching the next page
+    /// whenever the previous one is consumed, until the history is exhausted or the stop
+    /// condition of [options] is reached.
+    pub fn transactions_stream(
+        self,
+        public_account: PublicAccount,
+        options: TransactionHistoryOptions,
+    ) -> impl Stream<Item = Result<Box<dyn Transaction>>> {
+        self.__internal_transactions_stream(public_account, TRANSACTIONS_BY_ACCOUNT_ROUTE, options)
+    }
+
+    /// Streams the incoming transactions of [public_account], see
+    /// [AccountRoutes::transactions_stream].
+    pub fn incoming_transactions_stream(
+        self,
+        public_account: PublicAccount,
+        options: TransactionHistoryOptions,
+    ) -> impl Stream<Item = Result<Box<dyn Transaction>>> {
+        self.__internal_transactions_stream(public_account, INCOMING_TRANSACTIONS_ROUTE, options)
+    }
+
+    /// Streams the outgoing transactions of [public_account], see
+    /// [AccountRoutes::transactions_stream].
+    pub fn outgoing_transactions_stream(
+        self,
+        public_account: PublicAccount,
+        options: TransactionHistoryOptions,
+    ) -> impl Stream<Item = Result<Box<dyn Transaction>>> {
+        self.__internal_transactions_stream(public_account, OUTGOING_TRANSACTIONS_ROUTE, options)
+    }
+
+    fn __internal_transactions_stream(
+        self,
+        public_account: PublicAccount,
+        route: &'static str,
+        options: TransactionHistoryOptions,
+    ) -> impl Stream<Item = Result<Box<dyn Transaction>>> {
+        let page_size = options.page_size();
+        let ordering = options.ordering;
+
+        paginate_transactions(options, move |id| {
+            self.clone().__internal_transactions(
+                public_account,
+                route,
+                AccountTransactionsOption {
+                    page_size: Some(page_size),
+                    id,
+                    ordering: Some(ordering.as_str().to_string()),
+                },
+            )
+        })
+    }
+
     fn __internal_transactions(
         self,
         public_account: PublicAccount,
@@ -348,7 +436,7 @@ impl AccountRoutes {
 
             let mut transactions_info: Transactions = vec![];
             for transaction_dto in dto.into_iter() {
-                transactions_info.push(transaction_dto.compact()?)
+                transactions_info.push(transaction_dto.compact(self.__client().epoch)?)
             }
 
             Ok(transactions_info)
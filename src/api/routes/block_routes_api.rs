@@ -2,19 +2,41 @@
 // Use of this source code is governed by the Apache 2.0
 // license that can be found in the LICENSE file.
 
-use {::std::sync::Arc, reqwest::Method};
+use {
+    ::std::{future::Future, ops::RangeInclusive, sync::Arc, time::Duration},
+    futures_util::stream::{self, Stream, StreamExt, TryStreamExt},
+    reqwest::Method,
+};
 
 use crate::{
     api::{
-        dtos::{BlockInfoDto, TransactionDto},
-        request as __internal_request, ApiClient,
+        dtos::{BlockInfoDto, HeightInfoDto, StatementsDto, TransactionDto},
+        internally::AccountTransactionsOption,
+        paginate_transactions, request as __internal_request, ApiClient, TransactionHistoryOptions,
+        TransactionOrdering,
+    },
+    blockchain::{
+        average_block_time, block_pages, blocks_in_page, BlockInfo, BlockRangeOptions,
+        BlockWithTransactions, BLOCK_PAGE_SIZE,
     },
-    blockchain::BlockInfo,
+    error::Error,
+    errors_const::{ERR_INVALID_BLOCK_HEIGHT, ERR_INVALID_BLOCK_LIMIT, ERR_NOT_ENOUGH_BLOCKS},
     models::Result,
-    transaction::Transactions,
+    network::NetworkType,
+    receipt::{BalanceReceipt, BlockResolutions},
+    transaction::{median_fee_multiplier, FeeMultiplier, Height, Transaction, Transactions},
 };
 
-use super::{BLOCK_BY_HEIGHT_ROUTE, BLOCK_GET_TRANSACTION_ROUTE, BLOCK_INFO_ROUTE};
+use super::{
+    BLOCK_BY_HEIGHT_ROUTE, BLOCK_GET_TRANSACTION_ROUTE, BLOCK_INFO_ROUTE, BLOCK_RECEIPTS_ROUTE,
+    CHAIN_HEIGHT_ROUTE,
+};
+
+/// The number of most recent blocks used to estimate the network fee multiplier.
+const FEE_MULTIPLIER_BLOCKS: u64 = 25;
+
+/// The number of most recent blocks used to estimate the average block time.
+const BLOCK_TIME_BLOCKS: u64 = 100;
 
 /// Block ApiClient routes.
 ///
@@ -63,7 +85,9 @@ impl BlockRoutes {
     /// Returns a Future `Result` whose okay value is an [BlockInfo] the block information or
     /// whose error value is an `Error<Value>` describing the error that occurred.
     pub async fn get_block_by_height(self, height: u64) -> Result<BlockInfo> {
-        assert_ne!(height, 0, "Block height should not be zero.");
+        if height == 0 {
+            return Err(Error::from(ERR_INVALID_BLOCK_HEIGHT));
+        }
 
         let mut req =
             __internal_request::Request::new(Method::GET, BLOCK_BY_HEIGHT_ROUTE.to_string());
@@ -72,7 +96,7 @@ impl BlockRoutes {
 
         let dto: Result<BlockInfoDto> = req.execute(self.__client()).await;
 
-        Ok(dto?.compact()?)
+        Ok(dto?.compact(self.__client().epoch)?)
     }
 
     ///
@@ -118,9 +142,13 @@ impl BlockRoutes {
         height: u64,
         limit: i32,
     ) -> Result<Vec<BlockInfo>> {
-        assert_ne!(height, 0, "Block height should not be zero.");
+        if height == 0 {
+            return Err(Error::from(ERR_INVALID_BLOCK_HEIGHT));
+        }
 
-        assert_ne!(limit, 0, "Limit should not be zero.");
+        if limit <= 0 {
+            return Err(Error::from(ERR_INVALID_BLOCK_LIMIT));
+        }
 
         let limit = if limit < 25 {
             25
@@ -130,6 +158,117 @@ impl BlockRoutes {
             100
         };
 
+        self.__internal_blocks(height, limit).await
+    }
+
+    /// Streams the blocks of [range], in order of height.
+    ///
+    /// The range is split into pages of [BLOCK_PAGE_SIZE] blocks, and up to
+    /// `options.concurrency` pages are fetched at the same time. Exactly the blocks of
+    /// [range] are yielded, with their transactions when `options.with_transactions` is
+    /// set. An error is yielded if [range] is invalid or reaches past the chain height.
+    ///
+    /// # Example
+    /// ```
+    ///
+    ///use futures_util::StreamExt;
+    ///use xpx_chain_sdk::api::SiriusClient;
+    ///use xpx_chain_sdk::blockchain::BlockRangeOptions;
+    ///
+    ///#[tokio::main]
+    ///async fn main() {
+    /// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+    /// let client = SiriusClient::new_from_urls(&node_url);
+    ///
+    ///    let blocks = client.block.blocks(1..=130, BlockRangeOptions::default());
+    ///    futures_util::pin_mut!(blocks);
+    ///
+    ///    while let Some(block) = blocks.next().await {
+    ///        match block {
+    ///            Ok(block) => println!("{}", block.block),
+    ///            Err(err) => eprintln!("{:?}", err),
+    ///        }
+    ///    }
+    ///}
+    /// ```
+    pub fn blocks(
+        self,
+        range: RangeInclusive<u64>,
+        options: BlockRangeOptions,
+    ) -> impl Stream<Item = Result<BlockWithTransactions>> {
+        let (pages, error) = match block_pages(&range, BLOCK_PAGE_SIZE) {
+            Ok(pages) => (pages, None),
+            Err(err) => (vec![], Some(Err(Error::from(err)))),
+        };
+
+        let pages = stream::iter(pages)
+            .map(move |page_start| {
+                self.clone()
+                    .__internal_block_range_page(page_start, range.clone(), options)
+            })
+            .buffered(options.concurrency.max(1))
+            .flat_map(|page| {
+                stream::iter(match page {
+                    Ok(blocks) => blocks.into_iter().map(Ok).collect(),
+                    Err(err) => vec![Err(err)],
+                })
+            });
+
+        stream::iter(error).chain(pages)
+    }
+
+    /// Gets the blocks of [range], in order of height.
+    ///
+    /// See [BlockRoutes::blocks].
+    pub async fn get_blocks(
+        self,
+        range: RangeInclusive<u64>,
+        options: BlockRangeOptions,
+    ) -> Result<Vec<BlockWithTransactions>> {
+        self.blocks(range, options).try_collect().await
+    }
+
+    async fn __internal_block_range_page(
+        self,
+        page_start: u64,
+        range: RangeInclusive<u64>,
+        options: BlockRangeOptions,
+    ) -> Result<Vec<BlockWithTransactions>> {
+        let page = self
+            .clone()
+            .__internal_blocks(page_start, BLOCK_PAGE_SIZE)
+            .await?;
+
+        let blocks = blocks_in_page(page, page_start, BLOCK_PAGE_SIZE, &range)?;
+
+        let mut blocks_with_transactions = vec![];
+        for block in blocks.into_iter() {
+            let transactions = if options.with_transactions && block.num_transactions > 0 {
+                let transactions = self
+                    .clone()
+                    .get_block_transactions_stream(
+                        *block.height,
+                        TransactionHistoryOptions::new(TransactionOrdering::Ascending),
+                    )
+                    .try_collect()
+                    .await?;
+                Some(transactions)
+            } else if options.with_transactions {
+                Some(vec![])
+            } else {
+                None
+            };
+
+            blocks_with_transactions.push(BlockWithTransactions {
+                block,
+                transactions,
+            });
+        }
+
+        Ok(blocks_with_transactions)
+    }
+
+    async fn __internal_blocks(self, height: u64, limit: u64) -> Result<Vec<BlockInfo>> {
         let mut req = __internal_request::Request::new(Method::GET, BLOCK_INFO_ROUTE.to_string());
 
         req = req.with_path_param("height".to_string(), height.to_string());
@@ -140,12 +279,151 @@ impl BlockRoutes {
 
         let mut blocks_info: Vec<BlockInfo> = vec![];
         for block_inf in dto.into_iter() {
-            blocks_info.push(block_inf.compact()?);
+            blocks_info.push(block_inf.compact(self.__client().epoch)?);
         }
 
         Ok(blocks_info)
     }
 
+    /// Get the alias resolutions of a block.
+    ///
+    /// Gets the address and mosaic resolution statements of the block that has the given
+    /// height, that is the value every namespace alias used in the block referred to.
+    ///
+    /// # Inputs
+    ///
+    /// * `height` =    The height of the block.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is the [BlockResolutions] of the block or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_block_resolutions(self, height: u64) -> Result<BlockResolutions> {
+        let mut req =
+            __internal_request::Request::new(Method::GET, BLOCK_RECEIPTS_ROUTE.to_string());
+
+        req = req.with_path_param("height".to_string(), height.to_string());
+
+        let dto: StatementsDto = req.execute(self.__client()).await?;
+
+        dto.compact_resolutions(Height::new(height))
+    }
+
+    /// Get the balance receipts of a block.
+    ///
+    /// Gets the receipts of the block that has the given height that credited, debited or
+    /// transferred mosaics, such as harvest fees, lock funds and rental fees.
+    ///
+    /// # Inputs
+    ///
+    /// * `height` =    The height of the block.
+    ///
+    /// * `network_type` =    The network type the accounts of the receipts belong to.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is the Vector of [BalanceReceipt] of the
+    /// block or whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_block_balance_receipts(
+        self,
+        height: u64,
+        network_type: NetworkType,
+    ) -> Result<Vec<BalanceReceipt>> {
+        let (_, receipts) = self.get_block_statements(height, network_type).await?;
+
+        Ok(receipts)
+    }
+
+    /// Gets both the alias resolutions and the balance receipts of a block at once.
+    pub(crate) async fn get_block_statements(
+        self,
+        height: u64,
+        network_type: NetworkType,
+    ) -> Result<(BlockResolutions, Vec<BalanceReceipt>)> {
+        let mut req =
+            __internal_request::Request::new(Method::GET, BLOCK_RECEIPTS_ROUTE.to_string());
+
+        req = req.with_path_param("height".to_string(), height.to_string());
+
+        let dto: StatementsDto = req.execute(self.__client()).await?;
+
+        let resolutions = dto.compact_resolutions(Height::new(height))?;
+        let receipts = dto.compact_balance_receipts(Height::new(height), network_type)?;
+
+        Ok((resolutions, receipts))
+    }
+
+    /// Get the current fee multiplier of the network.
+    ///
+    /// Gets the median `fee_multiplier` of the most recent blocks, to be used with
+    /// `Transaction::set_max_fee_for`.
+    ///
+    /// # Example
+    /// ```
+    ///
+    ///use xpx_chain_sdk::api::SiriusClient;
+    ///
+    ///#[tokio::main]
+    ///async fn main() {
+    /// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+    /// let client = SiriusClient::new_from_urls(&node_url);
+    ///
+    ///    let fee_multiplier = client.block.get_fee_multiplier().await;
+    ///
+    ///    match fee_multiplier {
+    ///        Ok(resp_info) => println!("{}", resp_info),
+    ///        Err(err) => eprintln!("{:?}", err),
+    ///    }
+    ///}
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is the [FeeMultiplier] or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_fee_multiplier(self) -> Result<FeeMultiplier> {
+        let height = self.clone().get_chain_height().await?;
+
+        let from = height.saturating_sub(FEE_MULTIPLIER_BLOCKS - 1).max(1);
+
+        let blocks = self
+            .get_blocks_by_height_with_limit(from, FEE_MULTIPLIER_BLOCKS as i32)
+            .await?;
+
+        Ok(median_fee_multiplier(&blocks))
+    }
+
+    /// Get the average time between blocks.
+    ///
+    /// The average is estimated from the most recent blocks of the chain.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is the average block time or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_average_block_time(self) -> Result<Duration> {
+        let height = self.clone().get_chain_height().await?;
+
+        let from = height.saturating_sub(BLOCK_TIME_BLOCKS - 1).max(1);
+
+        let blocks = self
+            .get_blocks_by_height_with_limit(from, BLOCK_TIME_BLOCKS as i32)
+            .await?;
+
+        match average_block_time(&blocks) {
+            Some(block_time) => Ok(block_time),
+            None => Err(Error::from(ERR_NOT_ENOUGH_BLOCKS)),
+        }
+    }
+
+    pub(crate) async fn get_chain_height(self) -> Result<u64> {
+        let req = __internal_request::Request::new(Method::GET, CHAIN_HEIGHT_ROUTE.to_string());
+
+        let dto: HeightInfoDto = req.execute(self.__client()).await?;
+
+        Ok(*dto.compact().height)
+    }
+
     ///
     /// Get `Transactions` from a block
     ///
@@ -194,26 +472,64 @@ impl BlockRoutes {
         page_size: Option<i32>,
         id: Option<&str>,
     ) -> Result<Transactions> {
+        let options = AccountTransactionsOption::new(page_size, id, None)?;
+
+        self.__internal_block_transactions(height, options).await
+    }
+
+    /// Streams every transaction included in the block at [height], fetching the next
+    /// page whenever the previous one is consumed, until the block is exhausted or the
+    /// stop condition of [options] is reached.
+    pub fn get_block_transactions_stream(
+        self,
+        height: u64,
+        options: TransactionHistoryOptions,
+    ) -> impl Stream<Item = Result<Box<dyn Transaction>>> {
+        let page_size = options.page_size();
+        let ordering = options.ordering;
+
+        paginate_transactions(options, move |id| {
+            self.clone().__internal_block_transactions(
+                height,
+                AccountTransactionsOption {
+                    page_size: Some(page_size),
+                    id,
+                    ordering: Some(ordering.as_str().to_string()),
+                },
+            )
+        })
+    }
+
+    fn __internal_block_transactions(
+        self,
+        height: u64,
+        options: AccountTransactionsOption,
+    ) -> impl Future<Output = Result<Transactions>> {
         let mut req =
             __internal_request::Request::new(Method::GET, BLOCK_GET_TRANSACTION_ROUTE.to_string());
 
-        if let Some(ref s) = page_size {
+        if let Some(s) = options.page_size {
             req = req.with_query_param("pageSize".to_string(), s.to_string());
         }
-        if let Some(ref s) = id {
-            req = req.with_query_param("id".to_string(), s.to_string());
+        if let Some(s) = options.id {
+            req = req.with_query_param("id".to_string(), s);
+        }
+        if let Some(s) = options.ordering {
+            req = req.with_query_param("ordering".to_string(), s);
         }
         req = req
             .with_path_param("height".to_string(), height.to_string())
             .set_transaction_vec();
 
-        let dto: Vec<Box<dyn TransactionDto>> = req.execute(self.__client()).await?;
+        async move {
+            let dto: Vec<Box<dyn TransactionDto>> = req.execute(self.__client()).await?;
 
-        let mut transactions_info: Transactions = vec![];
-        for transaction_dto in dto.into_iter() {
-            transactions_info.push(transaction_dto.compact()?);
-        }
+            let mut transactions_info: Transactions = vec![];
+            for transaction_dto in dto.into_iter() {
+                transactions_info.push(transaction_dto.compact(self.__client().epoch)?);
+            }
 
-        Ok(transactions_info)
+            Ok(transactions_info)
+        }
     }
 }
@@ -9,12 +9,15 @@ use {::std::sync::Arc, reqwest::Method};
 use crate::{
     api::{
         request as __internal_request, sirius_client::ApiClient, BlockchainScoreDto, HeightInfoDto,
+        NetworkConfigDto,
     },
     blockchain::{BlockchainScore, HeightInfo, StorageInfo},
-    models::Result,
+    errors_const::ERR_INVALID_BLOCK_HEIGHT,
+    models::{error::Error, Result},
+    network::NetworkConfig,
 };
 
-use super::{CHAIN_HEIGHT_ROUTE, CHAIN_SCORE_ROUTE, CHAIN_STORAGE_ROUTE};
+use super::{CHAIN_CONFIG_ROUTE, CHAIN_HEIGHT_ROUTE, CHAIN_SCORE_ROUTE, CHAIN_STORAGE_ROUTE};
 
 /// Chain ApiClient routes.
 ///
@@ -137,4 +140,29 @@ impl ChainRoutes {
 
         req.execute(self.__client()).await
     }
+
+    /// Get the network configuration in effect at a height.
+    ///
+    /// # Inputs
+    ///
+    /// * `height` =    The height of the blockchain.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is the [NetworkConfig] in effect at
+    /// the height or whose error value is an `Error<Value>` describing the error that
+    /// occurred.
+    pub async fn get_network_config(self, height: u64) -> Result<NetworkConfig> {
+        if height == 0 {
+            return Err(Error::from(ERR_INVALID_BLOCK_HEIGHT));
+        }
+
+        let mut req = __internal_request::Request::new(Method::GET, CHAIN_CONFIG_ROUTE.to_string());
+
+        req = req.with_path_param("height".to_string(), height.to_string());
+
+        let dto: Result<NetworkConfigDto> = req.execute(self.__client()).await;
+
+        Ok(dto?.compact())
+    }
 }
@@ -20,11 +20,13 @@ pub const AGGREGATE_TRANSACTIONS_ROUTE: &str = "/account/{publicKey}/transaction
 pub const BLOCK_BY_HEIGHT_ROUTE: &str = "/block/{height}";
 pub const BLOCK_GET_TRANSACTION_ROUTE: &str = "/block/{height}/transactions";
 pub const BLOCK_INFO_ROUTE: &str = "/blocks/{height}/limit/{limit}";
+pub const BLOCK_RECEIPTS_ROUTE: &str = "/block/{height}/receipts";
 
 /// Chain routes.
 pub const CHAIN_STORAGE_ROUTE: &str = "/diagnostic/storage";
 pub const CHAIN_SCORE_ROUTE: &str = "/chain/score";
 pub const CHAIN_HEIGHT_ROUTE: &str = "/chain/height";
+pub const CHAIN_CONFIG_ROUTE: &str = "/config/{height}";
 
 ///  Exchange routes
 pub const EXCHANGE_ROUTE: &str = "/account/{account_id}/exchange";
@@ -44,6 +46,7 @@ pub const NAMESPACES_FROM_ACCOUNT_ROUTES: &str = "/account/{accountId}/namespace
 /// Node routes.
 pub const NODE_INFO: &str = "/node/info";
 pub const NODE_TIME: &str = "/node/time";
+pub const NODE_PEERS: &str = "/node/peers";
 
 /// Transaction routes.
 pub const TRANSACTIONS_ROUTE: &str = "/transaction";
@@ -10,8 +10,9 @@ use crate::api::OfferInfoDTOs;
 use crate::{
     account::PublicAccount,
     api::{request as __internal_request, sirius_client::ApiClient, ExchangeInfoDto},
-    exchange::{OfferInfos, OfferType, UserExchangeInfo},
+    exchange::{OfferInfos, OfferType, OrderBook, UserExchangeInfo},
     models::Result,
+    mosaic::MosaicId,
     network::NetworkType,
     AssetId, AssetIdType,
 };
@@ -99,4 +100,46 @@ impl ExchangeRoutes {
 
         Ok(offer_infos)
     }
+
+    /// Gets the buy and sell offers of a mosaic as an [OrderBook].
+    ///
+    /// The [asset_id] can be a `MosaicId` or a `NamespaceId` aliased to a mosaic.
+    pub async fn get_order_book(self, asset_id: impl AssetId) -> Result<OrderBook> {
+        let mosaic_id = match asset_id.get_type() {
+            AssetIdType::NamespaceIdType => {
+                self.clone()
+                    .__resolver_routes()
+                    .get_mosaic_info_by_asset_id(asset_id)
+                    .await?
+                    .mosaic_id
+            }
+            _ => MosaicId::from(asset_id.to_u64()),
+        };
+
+        let buy_offers = self
+            .clone()
+            .get_offers_or_empty(mosaic_id, OfferType::BuyOffer)
+            .await?;
+
+        let sell_offers = self
+            .get_offers_or_empty(mosaic_id, OfferType::SellOffer)
+            .await?;
+
+        Ok(OrderBook::new(mosaic_id, buy_offers, sell_offers))
+    }
+
+    /// Gets the offers of a mosaic, treating a side without offers as empty.
+    async fn get_offers_or_empty(
+        self,
+        mosaic_id: MosaicId,
+        offer_type: OfferType,
+    ) -> Result<OfferInfos> {
+        match self
+            .get_exchange_offer_by_asset_id(mosaic_id, offer_type)
+            .await
+        {
+            Err(err) if err.is_not_found() => Ok(vec![]),
+            other => other,
+        }
+    }
 }
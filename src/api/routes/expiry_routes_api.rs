@@ -0,0 +1,175 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use {
+    ::std::{collections::HashSet, time::Duration},
+    futures_util::stream::TryStreamExt,
+};
+
+use crate::{
+    account::PublicAccount,
+    api::{TransactionHistoryOptions, TransactionOrdering},
+    blockchain::Expiry,
+    models::Result,
+    mosaic::MosaicId,
+    transaction::{AggregateTransaction, Height, MosaicDefinitionTransaction, Transaction},
+    AssetIdType,
+};
+
+use super::{
+    account_routes_api::AccountRoutes, block_routes_api::BlockRoutes,
+    mosaic_routes_api::MosaicRoutes, namespace_routes_api::NamespaceRoutes,
+};
+
+/// Expiry ApiClient routes.
+///
+#[derive(Clone)]
+pub struct ExpiryRoutes(AccountRoutes, BlockRoutes, MosaicRoutes, NamespaceRoutes);
+
+/// Namespace and mosaic expiry related endpoints.
+///
+/// Times are estimated from the current chain height and the average block time.
+impl ExpiryRoutes {
+    pub(crate) fn new(
+        account_routes: AccountRoutes,
+        block_routes: BlockRoutes,
+        mosaic_routes: MosaicRoutes,
+        namespace_routes: NamespaceRoutes,
+    ) -> Self {
+        ExpiryRoutes(
+            account_routes,
+            block_routes,
+            mosaic_routes,
+            namespace_routes,
+        )
+    }
+
+    fn __account_routes(self) -> AccountRoutes {
+        self.0
+    }
+
+    fn __block_routes(self) -> BlockRoutes {
+        self.1
+    }
+
+    fn __mosaic_routes(self) -> MosaicRoutes {
+        self.2
+    }
+
+    fn __namespace_routes(self) -> NamespaceRoutes {
+        self.3
+    }
+
+    /// Gets the current chain height and the average block time.
+    async fn get_chain_clock(self) -> Result<(Height, Duration)> {
+        let height = self.clone().__block_routes().get_chain_height().await?;
+
+        let block_time = self.__block_routes().get_average_block_time().await?;
+
+        Ok((Height::new(height), block_time))
+    }
+
+    /// Gets the ids of the mosaics [owner] holds a balance of or defined, including the
+    /// ones defined by an aggregate.
+    async fn get_owned_mosaic_ids(self, owner: PublicAccount) -> Result<HashSet<MosaicId>> {
+        let mut mosaic_ids: HashSet<MosaicId> = match self
+            .clone()
+            .__account_routes()
+            .account_info(&owner.public_key_string())
+            .await
+        {
+            Ok(account_info) => account_info
+                .mosaics
+                .iter()
+                .filter(|mosaic| mosaic.asset_id.get_type() == AssetIdType::MosaicIdType)
+                .map(|mosaic| MosaicId::from(mosaic.asset_id.to_u64()))
+                .collect(),
+            Err(err) if err.is_not_found() => HashSet::new(),
+            Err(err) => return Err(err),
+        };
+
+        // An owner that distributed its whole supply holds no balance of the mosaic.
+        self.__account_routes()
+            .transactions_stream(
+                owner,
+                TransactionHistoryOptions::new(TransactionOrdering::Ascending),
+            )
+            .try_for_each(|transaction| {
+                mosaic_definition_ids(transaction.as_ref(), &mut mosaic_ids);
+                futures_util::future::ready(Ok(()))
+            })
+            .await?;
+
+        Ok(mosaic_ids)
+    }
+
+    /// Gets when every namespace and mosaic owned by [owner] expires, the soonest first.
+    ///
+    /// Mosaics that do not expire are left out. The mosaics are found from the balances
+    /// of the account and from the mosaic definitions in its transaction history.
+    pub async fn get_expiries(self, owner: PublicAccount) -> Result<Vec<Expiry>> {
+        let (current_height, block_time) = self.clone().get_chain_clock().await?;
+
+        let mut expiries: Vec<Expiry> = self
+            .clone()
+            .__namespace_routes()
+            .get_all_namespaces_from_account(owner.address)
+            .await?
+            .into_iter()
+            .map(|info| info.expiry(current_height, block_time))
+            .collect();
+
+        let mosaic_ids: Vec<MosaicId> = self
+            .clone()
+            .get_owned_mosaic_ids(owner)
+            .await?
+            .into_iter()
+            .collect();
+
+        if !mosaic_ids.is_empty() {
+            let owner_key = owner.public_key_string();
+
+            expiries.extend(
+                self.__mosaic_routes()
+                    .get_mosaics_info(mosaic_ids)
+                    .await?
+                    .into_iter()
+                    .filter(|info| info.owner.eq_ignore_ascii_case(&owner_key))
+                    .filter_map(|info| info.expiry(current_height, block_time)),
+            );
+        }
+
+        expiries.sort_by_key(|expiry| *expiry.end_height);
+
+        Ok(expiries)
+    }
+
+    /// Gets every namespace and mosaic owned by [owner] that expires within [window],
+    /// including the ones that are already expired, the soonest first.
+    pub async fn get_expiring_assets(
+        self,
+        owner: PublicAccount,
+        window: Duration,
+    ) -> Result<Vec<Expiry>> {
+        let expiries = self.get_expiries(owner).await?;
+
+        Ok(expiries
+            .into_iter()
+            .filter(|expiry| expiry.expires_within(window))
+            .collect())
+    }
+}
+
+/// Adds the ids of the mosaics defined by [transaction] to [mosaic_ids].
+fn mosaic_definition_ids(transaction: &dyn Transaction, mosaic_ids: &mut HashSet<MosaicId>) {
+    if let Some(aggregate) = transaction.try_downcast_ref::<AggregateTransaction>() {
+        for inner in aggregate.inner_transactions.iter() {
+            mosaic_definition_ids(inner.as_ref(), mosaic_ids);
+        }
+    } else if let Some(definition) = transaction.try_downcast_ref::<MosaicDefinitionTransaction>() {
+        mosaic_ids.insert(definition.mosaic_id);
+    }
+}
@@ -4,7 +4,10 @@
  * license that can be found in the LICENSE file.
  */
 
-use {::std::sync::Arc, reqwest::Method};
+use {
+    ::std::{collections::HashMap, sync::Arc},
+    reqwest::Method,
+};
 
 use crate::{
     account::Address,
@@ -19,10 +22,15 @@ use crate::{
         AddressMetadataInfoDto, MetadataDto, MosaicMetadataInfoDto, NamespaceMetadataInfoDto,
     },
     errors_const::{ERR_METADATA_EMPTY_ADDRESSES, ERR_METADATA_EMPTY_MOSAIC_IDS},
-    metadata::{AddressMetadataInfo, MetadataIds, MosaicMetadataInfo, NamespaceMetadataInfo},
+    metadata::{
+        metadata_modifications, AddressMetadataInfo, MetadataIds, MetadataInfo, MetadataTarget,
+        MosaicMetadataInfo, NamespaceMetadataInfo,
+    },
     models::Result,
     mosaic::MosaicId,
     namespace::NamespaceId,
+    network::NetworkType,
+    transaction::{Deadline, Transaction},
     AssetId,
 };
 
@@ -149,4 +157,69 @@ impl MetadataRoutes {
 
         Ok(namespace_info)
     }
+
+    /// Gets the metadata of an account, mosaic or namespace.
+    async fn get_metadata_info(self, target: MetadataTarget) -> Result<MetadataInfo> {
+        Ok(match target {
+            MetadataTarget::Address(address) => self.get_metadata_by_address(address).await?.info,
+            MetadataTarget::Mosaic(mosaic_id) => {
+                self.get_metadata_by_mosaic_id(mosaic_id).await?.info
+            }
+            MetadataTarget::Namespace(namespace_id) => {
+                self.get_metadata_by_namespace_id(namespace_id).await?.info
+            }
+        })
+    }
+
+    /// Gets all the metadata fields of an account, mosaic or namespace, empty when it
+    /// has no metadata.
+    pub async fn get_metadata_fields(
+        self,
+        target: impl Into<MetadataTarget>,
+    ) -> Result<HashMap<String, String>> {
+        match self.get_metadata_info(target.into()).await {
+            Ok(info) => Ok(info.fields),
+            Err(err) if err.is_not_found() => Ok(HashMap::new()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Gets the value of one metadata field of an account, mosaic or namespace.
+    pub async fn get_metadata_value(
+        self,
+        target: impl Into<MetadataTarget>,
+        key: &str,
+    ) -> Result<Option<String>> {
+        let mut fields = self.get_metadata_fields(target).await?;
+
+        Ok(fields.remove(key))
+    }
+
+    /// Creates the modify metadata transaction that turns the current metadata of an
+    /// account, mosaic or namespace into [desired], or `None` when it is up to date.
+    ///
+    /// The transaction only contains the fields that change, and its keys and values
+    /// are checked against the limits of the network.
+    pub async fn create_metadata_update(
+        self,
+        deadline: Deadline,
+        target: impl Into<MetadataTarget>,
+        desired: &HashMap<String, String>,
+        network_type: NetworkType,
+    ) -> Result<Option<Box<dyn Transaction>>> {
+        let target = target.into();
+
+        let current = self.get_metadata_fields(target).await?;
+
+        let modifications = metadata_modifications(&current, desired)?;
+        if modifications.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(target.create_transaction(
+            deadline,
+            modifications,
+            network_type,
+        )?))
+    }
 }
@@ -5,7 +5,7 @@
  */
 
 use {
-    ::std::{future::Future, pin::Pin, sync::Arc},
+    ::std::{collections::HashMap, future::Future, pin::Pin, sync::Arc},
     reqwest::Method,
 };
 
@@ -17,16 +17,22 @@ use crate::{
     },
     errors_const::{ERR_EMPTY_ADDRESSES_IDS, ERR_EMPTY_NAMESPACE_IDS},
     models::Result,
-    namespace::{NamespaceId, NamespaceIds, NamespaceInfo, NamespaceName},
+    namespace::{
+        generate_namespace_path, NamespaceId, NamespaceIds, NamespaceInfo, NamespaceLevel,
+        NamespaceName, NamespacePathStatus, NamespaceTree,
+    },
     network::NetworkType,
     AssetId,
 };
 
 use super::{
-    NAMESPACES_FROM_ACCOUNTS_ROUTE, NAMESPACES_FROM_ACCOUNT_ROUTES, NAMESPACE_NAMES_ROUTE,
-    NAMESPACE_ROUTE,
+    chain_routes_api::ChainRoutes, NAMESPACES_FROM_ACCOUNTS_ROUTE, NAMESPACES_FROM_ACCOUNT_ROUTES,
+    NAMESPACE_NAMES_ROUTE, NAMESPACE_ROUTE,
 };
 
+/// The page size used when fetching all the namespaces of an account.
+const NAMESPACES_PAGE_SIZE: i32 = 100;
+
 /// Namespace ApiClient routes.
 ///
 #[derive(Clone)]
@@ -194,4 +200,104 @@ impl NamespaceRoutes {
 
         Ok(namespace_info)
     }
+
+    /// Gets every namespace owned by the account, requesting as many pages as needed.
+    pub(crate) async fn get_all_namespaces_from_account(
+        self,
+        address: Address,
+    ) -> Result<Vec<NamespaceInfo>> {
+        let mut namespaces: Vec<NamespaceInfo> = vec![];
+        let mut last_id = None;
+        loop {
+            let page = self
+                .clone()
+                .get_namespaces_from_account(address, last_id, Some(NAMESPACES_PAGE_SIZE))
+                .await?;
+
+            let page_len = page.len();
+            last_id = page.last().map(|info| info.namespace_id);
+
+            namespaces.extend(page);
+
+            if page_len < NAMESPACES_PAGE_SIZE as usize {
+                break;
+            }
+        }
+
+        Ok(namespaces)
+    }
+
+    /// Gets a namespace together with all its sub-namespaces, including their
+    /// names, expiry heights and aliases.
+    pub async fn get_namespace_tree(self, namespace_id: NamespaceId) -> Result<NamespaceTree> {
+        let root = self.clone().get_namespace_info(namespace_id).await?;
+
+        let descendants: Vec<NamespaceInfo> = self
+            .clone()
+            .get_all_namespaces_from_account(root.owner.address)
+            .await?
+            .into_iter()
+            .filter(|info| {
+                info.levels.len() > root.levels.len() && info.levels.starts_with(&root.levels)
+            })
+            .collect();
+
+        let mut namespace_ids = vec![root.namespace_id];
+        namespace_ids.extend(descendants.iter().map(|info| info.namespace_id));
+
+        let names: HashMap<u64, String> = self
+            .get_namespaces_names(namespace_ids)
+            .await?
+            .into_iter()
+            .map(|ns_name| (ns_name.namespace_id.to_u64(), ns_name.name))
+            .collect();
+
+        Ok(NamespaceTree::build(&root, &descendants, &names))
+    }
+
+    /// Reports which levels of a dotted namespace name like `a.b.c` already exist
+    /// and who owns them.
+    pub async fn get_namespace_path_status(self, full_name: &str) -> Result<NamespacePathStatus> {
+        let namespace_ids = generate_namespace_path(full_name)?;
+
+        let height = ChainRoutes::new(self.__client())
+            .get_blockchain_height()
+            .await?
+            .height;
+
+        let mut levels: Vec<NamespaceLevel> = vec![];
+        let mut parent_exists = true;
+        for (index, (name, namespace_id)) in full_name.split('.').zip(namespace_ids).enumerate() {
+            // A sub-namespace can not exist when its parent does not.
+            let info = if parent_exists {
+                match self.clone().get_namespace_info(namespace_id).await {
+                    Ok(info) => Some(info),
+                    Err(err) if err.is_not_found() => None,
+                    Err(err) => return Err(err),
+                }
+            } else {
+                None
+            };
+
+            parent_exists = info.is_some();
+
+            levels.push(NamespaceLevel::new(
+                name.to_string(),
+                full_name
+                    .split('.')
+                    .take(index + 1)
+                    .collect::<Vec<_>>()
+                    .join("."),
+                namespace_id,
+                info,
+                height,
+            ));
+        }
+
+        Ok(NamespacePathStatus {
+            full_name: full_name.to_string(),
+            height,
+            levels,
+        })
+    }
 }
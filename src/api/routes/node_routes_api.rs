@@ -4,15 +4,19 @@
  * license that can be found in the LICENSE file.
  */
 
-use {::std::sync::Arc, reqwest::Method};
+use {
+    ::std::{sync::Arc, time::SystemTime},
+    reqwest::Method,
+};
 
 use crate::{
-    api::{request as __internal_request, sirius_client::ApiClient, NodeTimeDto},
-    models::Result,
-    node::{NodeInfo, NodeTime},
+    api::{request as __internal_request, sirius_client::ApiClient, NodeInfoDto, NodeTimeDto},
+    errors_const::ERR_INVALID_CLOCK_SAMPLES,
+    models::{error::Error, Result},
+    node::{ClockOffset, ClockSkewThresholds, NetworkTimeSource, NodeInfo, NodeTime},
 };
 
-use super::{NODE_INFO, NODE_TIME};
+use super::{NODE_INFO, NODE_PEERS, NODE_TIME};
 
 /// Node ApiClient routes.
 ///
@@ -60,7 +64,9 @@ impl NodeRoutes {
     pub async fn get_node_info(self) -> Result<NodeInfo> {
         let req = __internal_request::Request::new(Method::GET, NODE_INFO.to_string());
 
-        req.execute(self.__client()).await
+        let dto: Result<NodeInfoDto> = req.execute(self.__client()).await;
+
+        Ok(dto?.compact())
     }
 
     /// Get the node time.
@@ -95,4 +101,104 @@ impl NodeRoutes {
 
         Ok(dto?.compact())
     }
+
+    /// Get the peers of the node.
+    /// Gets the information of the nodes the node is connected to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///use xpx_chain_sdk::api::SiriusClient;
+    ///
+    ///#[tokio::main]
+    ///async fn main() {
+    /// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+    /// let client = SiriusClient::new_from_urls(&node_url);
+    ///
+    ///    let node_peers = client.node.get_node_peers().await;
+    ///
+    ///    match node_peers {
+    ///        Ok(resp_info) => {
+    ///            for peer in resp_info {
+    ///                println!("{}", peer)
+    ///            }
+    ///        }
+    ///        Err(err) => eprintln!("{:?}", err),
+    ///    }
+    ///}
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is a vector of [NodeInfo] or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_node_peers(self) -> Result<Vec<NodeInfo>> {
+        let req = __internal_request::Request::new(Method::GET, NODE_PEERS.to_string());
+
+        let dto: Result<Vec<NodeInfoDto>> = req.execute(self.__client()).await;
+
+        Ok(dto?.into_iter().map(NodeInfoDto::compact).collect())
+    }
+
+    /// Estimate the offset of the node clock from the local clock.
+    ///
+    /// Requests the node time [samples] times and keeps the estimate with the shortest
+    /// round trip, as it is the least affected by network delays.
+    ///
+    /// # Inputs
+    ///
+    /// * `samples` =    The number of node time requests.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is the [ClockOffset] of the node or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_clock_offset(self, samples: usize) -> Result<ClockOffset> {
+        if samples == 0 {
+            return Err(Error::from(ERR_INVALID_CLOCK_SAMPLES));
+        }
+
+        let mut best: Option<ClockOffset> = None;
+        for _ in 0..samples {
+            let request_sent = SystemTime::now();
+            let node_time = self.clone().get_node_time().await?;
+            let response_received = SystemTime::now();
+
+            let offset = ClockOffset::estimate(
+                request_sent,
+                &node_time,
+                response_received,
+                self.__client().epoch,
+            )?;
+            best = match best {
+                Some(current) if current.round_trip_ms <= offset.round_trip_ms => Some(current),
+                _ => Some(offset),
+            };
+        }
+
+        Ok(best.unwrap())
+    }
+
+    /// Get a time source following the node clock, to create deadlines with.
+    ///
+    /// # Inputs
+    ///
+    /// * `samples` =    The number of node time requests the offset is estimated from.
+    ///
+    /// * `thresholds` =    The skews reported as a warning or an error.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is a [NetworkTimeSource] or whose error
+    /// value is an `Error<Value>` describing the error that occurred, including a skew
+    /// exceeding the limit of [thresholds].
+    pub async fn get_network_time_source(
+        self,
+        samples: usize,
+        thresholds: ClockSkewThresholds,
+    ) -> Result<NetworkTimeSource> {
+        let offset = self.get_clock_offset(samples).await?;
+
+        Ok(NetworkTimeSource::new(offset, thresholds)?)
+    }
 }
@@ -0,0 +1,338 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use {
+    ::std::{
+        collections::{BTreeSet, HashMap, HashSet},
+        io::Write,
+    },
+    futures_util::{
+        pin_mut,
+        stream::{self, StreamExt, TryStreamExt},
+    },
+};
+
+use crate::{
+    account::{
+        history_entries, receipt_balance_changes, transaction_balance_changes, BalanceHistory,
+        HistoryRow, HistoryWriter, MosaicFormat, Portfolio, PublicAccount,
+    },
+    api::{TransactionHistoryOptions, TransactionOrdering},
+    blockchain::{BlockInfo, BLOCK_RANGE_CONCURRENCY},
+    error::Error,
+    models::Result,
+    mosaic::MosaicId,
+    receipt::{BalanceReceipt, BlockResolutions},
+    transaction::Transactions,
+};
+
+use super::{
+    account_routes_api::AccountRoutes, block_routes_api::BlockRoutes,
+    mosaic_routes_api::MosaicRoutes,
+};
+
+/// Portfolio ApiClient routes.
+///
+#[derive(Clone)]
+pub struct PortfolioRoutes(AccountRoutes, BlockRoutes, MosaicRoutes);
+
+/// Account balance history and portfolio related endpoints.
+///
+impl PortfolioRoutes {
+    pub(crate) fn new(
+        account_routes: AccountRoutes,
+        block_routes: BlockRoutes,
+        mosaic_routes: MosaicRoutes,
+    ) -> Self {
+        PortfolioRoutes(account_routes, block_routes, mosaic_routes)
+    }
+
+    fn __account_routes(self) -> AccountRoutes {
+        self.0
+    }
+
+    fn __block_routes(self) -> BlockRoutes {
+        self.1
+    }
+
+    fn __mosaic_routes(self) -> MosaicRoutes {
+        self.2
+    }
+
+    /// Get the balance history of an account.
+    ///
+    /// Rebuilds the balance timeline of every mosaic of the account from its confirmed
+    /// transactions, and the balance receipts of the blocks they were confirmed in.
+    /// Transaction fees are paid in [currency].
+    ///
+    /// Receipts of blocks without a transaction of the account, such as harvest fees or
+    /// expired locks, are not fetched. The opening balances are set so every timeline ends
+    /// at the current balance of the account.
+    ///
+    /// # Inputs
+    ///
+    /// * `public_account` =    The account.
+    ///
+    /// * `currency` =    The mosaic fees are paid in.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is the [BalanceHistory] of the account or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_balance_history(
+        self,
+        public_account: PublicAccount,
+        currency: MosaicId,
+    ) -> Result<BalanceHistory> {
+        let address = public_account.address;
+        let network_type = address.network_type();
+
+        let transactions: Transactions = self
+            .clone()
+            .__account_routes()
+            .transactions_stream(
+                public_account,
+                TransactionHistoryOptions::new(TransactionOrdering::Ascending),
+            )
+            .try_collect()
+            .await?;
+
+        let heights: BTreeSet<u64> = transactions
+            .iter()
+            .map(|transaction| *transaction.height())
+            .filter(|height| *height > 0)
+            .collect();
+
+        let block_routes = self.clone().__block_routes();
+        let blocks: Vec<(BlockInfo, BlockResolutions, Vec<BalanceReceipt>)> = stream::iter(heights)
+            .map(|height| {
+                let block_routes = block_routes.clone();
+                async move {
+                    let block = block_routes.clone().get_block_by_height(height).await?;
+                    let (resolutions, receipts) = block_routes
+                        .get_block_statements(height, network_type)
+                        .await?;
+                    Ok::<_, Error>((block, resolutions, receipts))
+                }
+            })
+            .buffered(BLOCK_RANGE_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        let blocks: HashMap<u64, (BlockInfo, BlockResolutions, Vec<BalanceReceipt>)> = blocks
+            .into_iter()
+            .map(|block| (*block.0.height, block))
+            .collect();
+
+        let mut changes = vec![];
+        for transaction in transactions.iter() {
+            let (block, resolutions, _) = match blocks.get(&*transaction.height()) {
+                Some(block) => block,
+                None => continue,
+            };
+
+            let resolved = resolutions
+                .resolve_transaction(transaction.as_ref())
+                .unwrap_or_else(|_| transaction.box_clone());
+
+            changes.extend(transaction_balance_changes(
+                &address,
+                resolved.as_ref(),
+                block.fee_multiplier.max(0) as u32,
+                currency,
+            ));
+        }
+
+        for (_, _, receipts) in blocks.values() {
+            changes.extend(receipt_balance_changes(&address, receipts));
+        }
+
+        let timestamps = blocks
+            .iter()
+            .map(|(height, (block, _, _))| (*height, block.timestamp))
+            .collect();
+
+        let mut history = BalanceHistory::new(address, changes, &timestamps);
+
+        match self
+            .__account_routes()
+            .account_info(&address.address_string())
+            .await
+        {
+            Ok(account_info) => history.reconcile(&account_info),
+            Err(err) if err.is_not_found() => {}
+            Err(err) => return Err(err),
+        }
+
+        Ok(history)
+    }
+
+    /// Export the transaction history of an account.
+    ///
+    /// Streams the confirmed transactions of the account, oldest first, flattens them into
+    /// [HistoryRow]s and writes them to [writer] as they arrive. Amounts are formatted with
+    /// the divisibility of their mosaic, and fees are paid in [currency].
+    ///
+    /// # Inputs
+    ///
+    /// * `public_account` =    The account.
+    ///
+    /// * `currency` =    The mosaic fees are paid in.
+    ///
+    /// * `writer` =    The [HistoryWriter] rows are written to.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is the number of rows written or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn export_transactions<W: Write>(
+        self,
+        public_account: PublicAccount,
+        currency: MosaicId,
+        writer: &mut HistoryWriter<W>,
+    ) -> Result<u64> {
+        let address = public_account.address;
+        let network_type = address.network_type();
+
+        let transactions = self.clone().__account_routes().transactions_stream(
+            public_account,
+            TransactionHistoryOptions::new(TransactionOrdering::Ascending),
+        );
+        pin_mut!(transactions);
+
+        let block_routes = self.clone().__block_routes();
+        let mut block: Option<(BlockInfo, BlockResolutions)> = None;
+        let mut formats: HashMap<MosaicId, MosaicFormat> = HashMap::new();
+        let mut rows = 0;
+
+        while let Some(transaction) = transactions.try_next().await? {
+            let height = *transaction.height();
+
+            let is_cached = matches!(block, Some((ref info, _)) if *info.height == height);
+            if height > 0 && !is_cached {
+                let info = block_routes.clone().get_block_by_height(height).await?;
+                let (resolutions, _) = block_routes
+                    .clone()
+                    .get_block_statements(height, network_type)
+                    .await?;
+                block = Some((info, resolutions));
+            }
+
+            let (resolved, fee_multiplier, timestamp) = match block {
+                Some((ref info, ref resolutions)) if *info.height == height => (
+                    resolutions
+                        .resolve_transaction(transaction.as_ref())
+                        .unwrap_or_else(|_| transaction.box_clone()),
+                    info.fee_multiplier.max(0) as u32,
+                    Some(info.timestamp),
+                ),
+                _ => (transaction, 0, None),
+            };
+
+            let entries = history_entries(&address, resolved.as_ref(), fee_multiplier, currency);
+
+            let mut missing: HashSet<MosaicId> = entries
+                .iter()
+                .filter_map(|entry| entry.mosaic_id)
+                .filter(|mosaic_id| !formats.contains_key(mosaic_id))
+                .collect();
+            if !formats.contains_key(&currency) {
+                missing.insert(currency);
+            }
+            if !missing.is_empty() {
+                formats.extend(self.clone().__mosaic_formats(missing).await?);
+            }
+
+            for entry in entries.iter() {
+                writer.write_row(&HistoryRow::new(entry, timestamp, &formats, currency))?;
+                rows += 1;
+            }
+        }
+
+        Ok(rows)
+    }
+
+    async fn __mosaic_formats(
+        self,
+        mosaic_ids: HashSet<MosaicId>,
+    ) -> Result<HashMap<MosaicId, MosaicFormat>> {
+        let mosaic_ids: Vec<MosaicId> = mosaic_ids.into_iter().collect();
+
+        let mut formats: HashMap<MosaicId, MosaicFormat> = mosaic_ids
+            .iter()
+            .map(|mosaic_id| {
+                (
+                    *mosaic_id,
+                    MosaicFormat {
+                        name: None,
+                        divisibility: 0,
+                    },
+                )
+            })
+            .collect();
+
+        match self
+            .clone()
+            .__mosaic_routes()
+            .get_mosaics_info(mosaic_ids.clone())
+            .await
+        {
+            Ok(mosaics_info) => {
+                for mosaic_info in mosaics_info.iter() {
+                    if let Some(format) = formats.get_mut(&mosaic_info.mosaic_id) {
+                        format.divisibility = mosaic_info.divisibility();
+                    }
+                }
+            }
+            Err(err) if err.is_not_found() => {}
+            Err(err) => return Err(err),
+        }
+
+        match self.__mosaic_routes().get_mosaics_names(mosaic_ids).await {
+            Ok(mosaics_names) => {
+                for mosaic_names in mosaics_names.into_iter() {
+                    if let Some(format) = formats.get_mut(&mosaic_names.mosaic_id) {
+                        format.name = mosaic_names.names.into_iter().next();
+                    }
+                }
+            }
+            Err(err) if err.is_not_found() => {}
+            Err(err) => return Err(err),
+        }
+
+        Ok(formats)
+    }
+
+    /// Get the portfolio of a set of accounts.
+    ///
+    /// Gets the mosaics held by the accounts, with the namespace names linked to them.
+    ///
+    /// # Inputs
+    ///
+    /// * `accounts_id` =    The public keys or addresses of the accounts.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is the [Portfolio] of the accounts or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_portfolio(self, accounts_id: Vec<&str>) -> Result<Portfolio> {
+        let accounts_info = self
+            .clone()
+            .__account_routes()
+            .accounts_info(accounts_id)
+            .await?;
+
+        let mosaic_ids = Portfolio::new(&accounts_info, &[]).mosaic_ids();
+
+        let names = if mosaic_ids.is_empty() {
+            vec![]
+        } else {
+            self.__mosaic_routes().get_mosaics_names(mosaic_ids).await?
+        };
+
+        Ok(Portfolio::new(&accounts_info, &names))
+    }
+}
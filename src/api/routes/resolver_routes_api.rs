@@ -4,34 +4,55 @@
  * license that can be found in the LICENSE file.
  */
 
-use ::std::sync::Arc;
+use {
+    ::std::{
+        collections::{HashMap, HashSet},
+        sync::Arc,
+    },
+    futures_util::future::try_join_all,
+};
 
 use crate::{
-    api::{valid_vec_len, ApiClient},
+    account::Address,
+    api::{valid_vec_len, AliasCache, ApiClient, ALIAS_CACHE_HEIGHT_TTL},
     error::Error,
-    errors_const::{ERR_INVALID_ASSET_ID, ERR_INVALID_NAMESPACE_ALIASED},
+    errors_const::{
+        ERR_EMPTY_ADDRESSES_IDS, ERR_INVALID_ASSET_ID, ERR_INVALID_NAMESPACE_ADDRESS_ALIASED,
+        ERR_INVALID_NAMESPACE_ALIASED, ERR_TRANSACTION_NOT_CONFIRMED,
+    },
     models::Result,
     mosaic::{MosaicId, MosaicInfo},
-    namespace::NamespaceId,
+    namespace::{NamespaceAlias, NamespaceId},
+    transaction::Transaction,
     AssetId, AssetIdType,
 };
 
-use super::{mosaic_routes_api::MosaicRoutes, namespace_routes_api::NamespaceRoutes};
+use super::{
+    block_routes_api::BlockRoutes, chain_routes_api::ChainRoutes, mosaic_routes_api::MosaicRoutes,
+    namespace_routes_api::NamespaceRoutes,
+};
 
 /// Node ApiClient routes.
 ///
 #[derive(Clone)]
-pub struct ResolverRoutes(Arc<ApiClient>, NamespaceRoutes, MosaicRoutes);
+pub struct ResolverRoutes(
+    Arc<ApiClient>,
+    NamespaceRoutes,
+    MosaicRoutes,
+    Arc<AliasCache>,
+);
 
 /// Resolver related endpoints.
 ///
+/// Resolved namespace aliases are kept in the [AliasCache] of the client.
 impl ResolverRoutes {
     pub(crate) fn new(
         client: Arc<ApiClient>,
         namespace_routes: NamespaceRoutes,
         mosaic_routes: MosaicRoutes,
+        alias_cache: Arc<AliasCache>,
     ) -> Self {
-        ResolverRoutes(client, namespace_routes, mosaic_routes)
+        ResolverRoutes(client, namespace_routes, mosaic_routes, alias_cache)
     }
 
     fn __client(&self) -> Arc<ApiClient> {
@@ -46,67 +67,184 @@ impl ResolverRoutes {
         self.2
     }
 
-    pub async fn get_mosaic_info_by_asset_id(self, asset_id: impl AssetId) -> Result<MosaicInfo> {
+    fn __alias_cache(&self) -> Arc<AliasCache> {
+        Arc::clone(&self.3)
+    }
+
+    /// Updates the height of the alias cache when it is stale, so that the aliases of
+    /// expired namespaces are dropped.
+    async fn __update_cache_height(&self) -> Result<()> {
+        let alias_cache = self.__alias_cache();
+
+        if alias_cache.is_height_stale(ALIAS_CACHE_HEIGHT_TTL) {
+            let height_info = ChainRoutes::new(self.__client())
+                .get_blockchain_height()
+                .await?;
+            alias_cache.update_height(height_info.height);
+        }
+
+        Ok(())
+    }
+
+    /// Gets the alias of a namespace, from the cache when it was resolved before.
+    pub async fn get_namespace_alias(self, namespace_id: NamespaceId) -> Result<NamespaceAlias> {
+        self.__update_cache_height().await?;
+
+        let alias_cache = self.__alias_cache();
+
+        if let Some(alias) = alias_cache.get(namespace_id) {
+            return Ok(alias);
+        }
+
+        let namespace_info = self
+            .__namespace_routes()
+            .get_namespace_info(namespace_id)
+            .await?;
+
+        alias_cache.insert(&namespace_info);
+
+        Ok(namespace_info.alias)
+    }
+
+    /// Gets the aliases of the given namespaces keyed by namespace id, requesting every
+    /// namespace that is not cached only once.
+    async fn get_namespace_aliases(
+        self,
+        namespace_ids: Vec<NamespaceId>,
+    ) -> Result<HashMap<u64, NamespaceAlias>> {
+        self.__update_cache_height().await?;
+
+        let mut seen = HashSet::new();
+
+        let requests = namespace_ids
+            .into_iter()
+            .filter(|namespace_id| seen.insert(namespace_id.to_u64()))
+            .map(|namespace_id| {
+                let routes = self.clone();
+                async move {
+                    let alias = routes.get_namespace_alias(namespace_id).await?;
+                    Ok::<_, Error>((namespace_id.to_u64(), alias))
+                }
+            });
+
+        Ok(try_join_all(requests).await?.into_iter().collect())
+    }
+
+    /// Resolves an [AssetId] to the [MosaicId] it refers to.
+    pub async fn resolve_mosaic_id(self, asset_id: impl AssetId) -> Result<MosaicId> {
         match asset_id.get_type() {
             AssetIdType::NamespaceIdType => {
                 let namespace_id = NamespaceId::from(asset_id.to_uint64());
 
-                let namespace_info = self
-                    .clone()
-                    .__namespace_routes()
-                    .get_namespace_info(namespace_id)
-                    .await?;
+                match self.get_namespace_alias(namespace_id).await?.mosaic_id {
+                    Some(mosaic_id) => Ok(mosaic_id),
+                    None => Err(Error::from(ERR_INVALID_NAMESPACE_ALIASED)),
+                }
+            }
+            AssetIdType::MosaicIdType => Ok(MosaicId::from(asset_id.to_u64())),
+        }
+    }
 
-                if namespace_info.alias.mosaic_id.is_none() {
-                    return Err(Error::from(ERR_INVALID_NAMESPACE_ALIASED));
-                };
+    /// Resolves a vector of mixed [AssetId] to the [MosaicId] they refer to, in the same order.
+    pub async fn resolve_mosaic_ids(self, asset_ids: Vec<impl AssetId>) -> Result<Vec<MosaicId>> {
+        valid_vec_len(&asset_ids, ERR_INVALID_ASSET_ID)?;
 
-                self.__mosaic_routes()
-                    .get_mosaic_info(namespace_info.alias.mosaic_id.unwrap())
-                    .await
-            }
-            AssetIdType::MosaicIdType => {
-                let mosaic_id = MosaicId::from(asset_id.to_u64());
-                self.__mosaic_routes().get_mosaic_info(mosaic_id).await
-            }
+        let namespace_ids = asset_ids
+            .iter()
+            .filter(|asset_id| asset_id.get_type() == AssetIdType::NamespaceIdType)
+            .map(|asset_id| NamespaceId::from(asset_id.to_uint64()))
+            .collect();
+
+        let aliases = self.get_namespace_aliases(namespace_ids).await?;
+
+        asset_ids
+            .iter()
+            .map(|asset_id| match asset_id.get_type() {
+                AssetIdType::NamespaceIdType => aliases
+                    .get(&asset_id.to_u64())
+                    .and_then(|alias| alias.mosaic_id)
+                    .ok_or_else(|| Error::from(ERR_INVALID_NAMESPACE_ALIASED)),
+                AssetIdType::MosaicIdType => Ok(MosaicId::from(asset_id.to_u64())),
+            })
+            .collect()
+    }
+
+    /// Resolves a namespace alias [Address], like the recipient of a transfer created with
+    /// `TransferTransaction::with_namespace`, to the account [Address] it refers to.
+    ///
+    /// An account address is returned as it is.
+    pub async fn resolve_address(self, address: Address) -> Result<Address> {
+        let namespace_id = match address.to_namespace_id() {
+            Some(namespace_id) => namespace_id,
+            None => return Ok(address),
+        };
+
+        match self.get_namespace_alias(namespace_id).await?.address {
+            Some(address) => Ok(address),
+            None => Err(Error::from(ERR_INVALID_NAMESPACE_ADDRESS_ALIASED)),
         }
     }
 
+    /// Resolves a vector of addresses to account addresses, in the same order.
+    pub async fn resolve_addresses(self, addresses: Vec<Address>) -> Result<Vec<Address>> {
+        valid_vec_len(&addresses, ERR_EMPTY_ADDRESSES_IDS)?;
+
+        let namespace_ids = addresses
+            .iter()
+            .filter_map(|address| address.to_namespace_id())
+            .collect();
+
+        let aliases = self.get_namespace_aliases(namespace_ids).await?;
+
+        addresses
+            .into_iter()
+            .map(|address| match address.to_namespace_id() {
+                Some(namespace_id) => aliases
+                    .get(&namespace_id.to_u64())
+                    .and_then(|alias| alias.address)
+                    .ok_or_else(|| Error::from(ERR_INVALID_NAMESPACE_ADDRESS_ALIASED)),
+                None => Ok(address),
+            })
+            .collect()
+    }
+
+    /// Resolves the namespace aliases of a confirmed transaction as they were at its height.
+    ///
+    /// The aliases are resolved with the resolution statements of the block of the
+    /// transaction instead of the current alias state, so a transfer stays correct after
+    /// its alias is moved.
+    pub async fn resolve_transaction_aliases(
+        self,
+        transaction: &dyn Transaction,
+    ) -> Result<Box<dyn Transaction>> {
+        let height = transaction.height();
+
+        if *height == 0 {
+            return Err(Error::from(ERR_TRANSACTION_NOT_CONFIRMED));
+        }
+
+        let resolutions = BlockRoutes::new(self.__client())
+            .get_block_resolutions(*height)
+            .await?;
+
+        Ok(resolutions.resolve_transaction(transaction)?)
+    }
+
+    pub async fn get_mosaic_info_by_asset_id(self, asset_id: impl AssetId) -> Result<MosaicInfo> {
+        let mosaic_id = self.clone().resolve_mosaic_id(asset_id).await?;
+
+        self.__mosaic_routes().get_mosaic_info(mosaic_id).await
+    }
+
     pub async fn get_mosaic_infos_by_asset_ids(
         self,
         asset_ids: Vec<impl AssetId>,
     ) -> Result<Vec<MosaicInfo>> {
-        valid_vec_len(&asset_ids, ERR_INVALID_ASSET_ID)?;
+        let mut mosaic_ids = self.clone().resolve_mosaic_ids(asset_ids).await?;
 
-        let mut namespace_ids = vec![];
-        let mut mosaic_ids = vec![];
+        let mut seen = HashSet::new();
+        mosaic_ids.retain(|mosaic_id| seen.insert(*mosaic_id));
 
-        for asset_id in &asset_ids {
-            match asset_id.get_type() {
-                AssetIdType::NamespaceIdType => {
-                    let namespace_id = NamespaceId::from(asset_id.to_uint64());
-                    namespace_ids.push(namespace_id);
-                }
-                AssetIdType::MosaicIdType => {
-                    let mosaic_id = MosaicId::from(asset_id.to_uint64());
-                    mosaic_ids.push(mosaic_id);
-                }
-            }
-        }
-
-        if !mosaic_ids.is_empty() {
-            self.__mosaic_routes().get_mosaics_info(mosaic_ids).await
-        } else {
-            let mut mosaic_infos = vec![];
-
-            for namespace_id in namespace_ids {
-                mosaic_infos.push(
-                    self.clone()
-                        .get_mosaic_info_by_asset_id(namespace_id)
-                        .await?,
-                );
-            }
-            Ok(mosaic_infos)
-        }
+        self.__mosaic_routes().get_mosaics_info(mosaic_ids).await
     }
 }
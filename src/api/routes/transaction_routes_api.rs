@@ -21,11 +21,15 @@ use crate::{
         sirius_client::ApiClient,
         TransactionDto, TransactionStatusDto,
     },
-    errors_const::{ERR_EMPTY_TRANSACTION_HASHES, ERR_EMPTY_TRANSACTION_IDS},
-    models::Result,
+    errors_const::{
+        ERR_DEADLINE_EPOCH_MISMATCH, ERR_EMPTY_TRANSACTION_HASHES, ERR_EMPTY_TRANSACTION_IDS,
+    },
+    models::{error::Error, Result},
+    node::CLOCK_SKEW_LIMIT,
     transaction::{
-        CosignatureSignedTransaction, SignedTransaction, Transaction, TransactionHashes,
-        TransactionIds, TransactionStatus, Transactions, TransactionsStatus,
+        BlockchainTimestamp, CosignatureSignedTransaction, Deadline, SignedTransaction,
+        Transaction, TransactionHashes, TransactionIds, TransactionStatus, Transactions,
+        TransactionsStatus, TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS,
     },
 };
 
@@ -90,7 +94,7 @@ impl TransactionRoutes {
 
         let dto: Result<TransactionStatusDto> = req.execute(self.__client()).await;
 
-        Ok(dto?.compact()?)
+        Ok(dto?.compact(self.__client().epoch)?)
     }
 
     /// Get transactions status.
@@ -147,9 +151,10 @@ impl TransactionRoutes {
 
         let dto: Vec<TransactionStatusDto> = req.execute(self.__client()).await?;
 
+        let epoch = self.__client().epoch;
         let statuses: TransactionsStatus = dto
             .into_iter()
-            .map(move |status_dto| status_dto.compact().unwrap())
+            .map(move |status_dto| status_dto.compact(epoch).unwrap())
             .collect();
 
         Ok(statuses)
@@ -202,7 +207,7 @@ impl TransactionRoutes {
 
         let transaction: Box<dyn TransactionDto> = req.execute(self.__client()).await?;
 
-        Ok(transaction.compact()?)
+        Ok(transaction.compact(self.__client().epoch)?)
     }
 
     /// Get [Transactions] information.
@@ -258,7 +263,7 @@ impl TransactionRoutes {
 
         let mut transactions_info: Transactions = vec![];
         for transaction_dto in dto.into_iter() {
-            transactions_info.push(transaction_dto.compact()?);
+            transactions_info.push(transaction_dto.compact(self.__client().epoch)?);
         }
 
         Ok(transactions_info)
@@ -343,6 +348,8 @@ impl TransactionRoutes {
         self,
         transaction_signed: &SignedTransaction,
     ) -> Result<AnnounceTransactionInfo> {
+        self.__validate_deadline(transaction_signed)?;
+
         self.__announce_transaction(transaction_signed, TRANSACTIONS_ROUTE)
             .await
     }
@@ -351,6 +358,8 @@ impl TransactionRoutes {
         self,
         signed_transaction: &SignedTransaction,
     ) -> Result<AnnounceTransactionInfo> {
+        self.__validate_deadline(signed_transaction)?;
+
         self.__announce_transaction(signed_transaction, ANNOUNCE_AGGREGATE_ROUTE)
             .await
     }
@@ -363,6 +372,45 @@ impl TransactionRoutes {
             .await
     }
 
+    /// Rejects a transaction whose deadline was encoded on the default epoch while the
+    /// network counts its time from another one, as the node would read it years off.
+    ///
+    /// A deadline is taken to be on an epoch when it falls within the maximum deadline
+    /// from now on it, give or take `CLOCK_SKEW_LIMIT`.
+    fn __validate_deadline(&self, signed_transaction: &SignedTransaction) -> Result<()> {
+        let client = self.__client();
+
+        let timestamp = match signed_transaction.payload_deadline() {
+            Some(timestamp) => timestamp,
+            None => return Ok(()),
+        };
+
+        let window = client
+            .max_deadline
+            .checked_add(CLOCK_SKEW_LIMIT)
+            .unwrap_or(client.max_deadline);
+
+        let is_on_epoch = |timestamp: &BlockchainTimestamp, epoch: i64| {
+            let remaining =
+                Deadline::from_blockchain_timestamp_with_epoch(timestamp.clone(), epoch)
+                    .remaining();
+
+            match remaining.to_std() {
+                Ok(remaining) => remaining <= window,
+                Err(_) => matches!((-remaining).to_std(), Ok(late) if late < CLOCK_SKEW_LIMIT),
+            }
+        };
+
+        if client.epoch != TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS
+            && !is_on_epoch(&timestamp, client.epoch)
+            && is_on_epoch(&timestamp, TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS)
+        {
+            return Err(Error::from(ERR_DEADLINE_EPOCH_MISMATCH));
+        }
+
+        Ok(())
+    }
+
     fn __announce_transaction<T>(
         self,
         tx: T,
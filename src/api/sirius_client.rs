@@ -4,24 +4,41 @@
  * license that can be found in the LICENSE file.
  */
 
-use {::std::sync::Arc, reqwest::Client as ReqwestClient};
+use {
+    ::std::{sync::Arc, time::Duration},
+    reqwest::Client as ReqwestClient,
+};
 
-use crate::{models::error::Result, network::NetworkType, transaction::HashValue};
+use crate::{
+    models::error::Result,
+    network::{NetworkProperties, NetworkType},
+    node::{ClockSkewThresholds, NetworkTimeSource, CLOCK_OFFSET_SAMPLES},
+    transaction::{
+        Deadline, HashValue, Timestamp, MAX_DEADLINE_WINDOW, TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS,
+    },
+};
 
-use super::routes::{
-    account_routes_api::AccountRoutes, block_routes_api::BlockRoutes,
-    chain_routes_api::ChainRoutes, exchange_routes_api::ExchangeRoutes,
-    metadata_routes_api::MetadataRoutes, mosaic_routes_api::MosaicRoutes,
-    namespace_routes_api::NamespaceRoutes, node_routes_api::NodeRoutes,
-    resolver_routes_api::ResolverRoutes, transaction_routes_api::TransactionRoutes,
+use super::{
+    routes::{
+        account_routes_api::AccountRoutes, block_routes_api::BlockRoutes,
+        chain_routes_api::ChainRoutes, exchange_routes_api::ExchangeRoutes,
+        expiry_routes_api::ExpiryRoutes, metadata_routes_api::MetadataRoutes,
+        mosaic_routes_api::MosaicRoutes, namespace_routes_api::NamespaceRoutes,
+        node_routes_api::NodeRoutes, portfolio_routes_api::PortfolioRoutes,
+        resolver_routes_api::ResolverRoutes, transaction_routes_api::TransactionRoutes,
+    },
+    AliasCache,
 };
 
 #[derive(Clone, Serialize)]
 pub struct SiriusClient {
     generation_hash: HashValue,
     network_type: NetworkType,
+    network_properties: NetworkProperties,
     #[serde(skip_serializing)]
     client: Arc<ApiClient>,
+    #[serde(skip_serializing)]
+    alias_cache: Arc<AliasCache>,
 }
 
 impl SiriusClient {
@@ -49,6 +66,23 @@ impl SiriusClient {
         ))
     }
 
+    pub fn expiry_api(&self) -> Box<ExpiryRoutes> {
+        Box::new(ExpiryRoutes::new(
+            *self.account_api(),
+            *self.block_api(),
+            *self.mosaic_api(),
+            *self.namespace_api(),
+        ))
+    }
+
+    pub fn portfolio_api(&self) -> Box<PortfolioRoutes> {
+        Box::new(PortfolioRoutes::new(
+            *self.account_api(),
+            *self.block_api(),
+            *self.mosaic_api(),
+        ))
+    }
+
     pub fn node_api(&self) -> Box<NodeRoutes> {
         Box::new(NodeRoutes::new(self.__client()))
     }
@@ -70,6 +104,7 @@ impl SiriusClient {
             self.__client(),
             *self.namespace_api(),
             *self.mosaic_api(),
+            self.alias_cache(),
         ))
     }
 
@@ -91,7 +126,9 @@ impl SiriusClient {
         Box::new(SiriusClient {
             generation_hash: HashValue::zero(),
             network_type: Default::default(),
+            network_properties: Default::default(),
             client: arc_client,
+            alias_cache: Arc::new(AliasCache::new()),
         })
     }
 
@@ -102,12 +139,54 @@ impl SiriusClient {
                 self.generation_hash = info.generation_hash;
                 self.network_type = info.network_type;
 
-                Ok(())
+                self.__network_properties(info.timestamp).await
             }
             Err(err) => Err(err),
         }
     }
 
+    /// Loads the [NetworkProperties] from the configuration in effect at the current
+    /// height. Nodes serving no configuration get the default properties.
+    async fn __network_properties(&mut self, nemesis_timestamp: Timestamp) -> Result<()> {
+        let height = self.chain_api().get_blockchain_height().await?;
+
+        let mut properties = match self.chain_api().get_network_config(*height.height).await {
+            Ok(config) => NetworkProperties::from_config(
+                self.network_type,
+                self.generation_hash,
+                &config.network_config,
+            )?,
+            Err(err) if err.is_not_found() => NetworkProperties {
+                network_type: self.network_type,
+                generation_hash: self.generation_hash,
+                ..Default::default()
+            },
+            Err(err) => return Err(err),
+        };
+
+        properties.nemesis_timestamp = Some(
+            nemesis_timestamp
+                .to_blockchain_timestamp()
+                .to_timestamp_with_epoch(properties.epoch),
+        );
+
+        match self
+            .mosaic_api()
+            .get_mosaic_info(properties.currency_mosaic_id)
+            .await
+        {
+            Ok(mosaic_info) => properties.currency_divisibility = mosaic_info.divisibility(),
+            Err(err) if err.is_not_found() => {}
+            Err(err) => return Err(err),
+        }
+
+        // Routes created from now on decode timestamps on the network epoch.
+        self.client = Arc::new(self.client.with_network_properties(&properties));
+        self.network_properties = properties;
+
+        Ok(())
+    }
+
     pub async fn new_from_urls<T: AsRef<str>>(urls: &[T]) -> Result<Box<Self>> {
         //TODO
         let url_node = String::from(urls[0].as_ref());
@@ -140,9 +219,40 @@ impl SiriusClient {
         self.network_type
     }
 
+    /// The properties of the network, loaded from the node at connect time.
+    pub fn network_properties(&self) -> &NetworkProperties {
+        &self.network_properties
+    }
+
+    /// Creates a `Deadline` [duration] from now on the network epoch, to build the
+    /// transactions announced through this client with.
+    ///
+    /// The epoch-less `Deadline` constructors use the epoch of the ProximaX public
+    /// networks; announcing a transaction built with one on a network with another epoch
+    /// fails with `ERR_DEADLINE_EPOCH_MISMATCH`.
+    pub fn deadline(&self, duration: chrono::Duration) -> crate::Result<Deadline> {
+        self.network_properties.deadline(duration)
+    }
+
+    /// The cache of namespace aliases resolved by the `resolver_api`.
+    pub fn alias_cache(&self) -> Arc<AliasCache> {
+        Arc::clone(&self.alias_cache)
+    }
+
     pub fn node(&self) -> &str {
         &self.client.base_path
     }
+
+    /// Estimates the clock offset of the node, to create deadlines on the network time
+    /// with `Deadline::new_with_time_source`.
+    ///
+    /// Fails when the skew exceeds `CLOCK_SKEW_LIMIT`; a skew above `CLOCK_SKEW_WARNING`
+    /// is reported by `NetworkTimeSource::is_skewed`.
+    pub async fn network_time_source(&self) -> Result<NetworkTimeSource> {
+        self.node_api()
+            .get_network_time_source(CLOCK_OFFSET_SAMPLES, ClockSkewThresholds::default())
+            .await
+    }
 }
 
 impl core::fmt::Display for SiriusClient {
@@ -159,23 +269,42 @@ pub(crate) struct ApiClient {
     pub base_path: String,
     pub client: ReqwestClient,
     pub user_agent: Option<String>,
+    /// The time, in milliseconds since the Unix epoch, the network time counts from. Used
+    /// to decode the timestamps and deadlines served by the node.
+    pub epoch: i64,
+    /// The maximum time between the announcement of a transaction and its deadline.
+    pub max_deadline: Duration,
 }
 
 impl ApiClient {
-    fn new_from_url(url: String) -> Self {
+    pub(crate) fn new_from_url(url: String) -> Self {
         let client = ReqwestClient::new();
         ApiClient {
             base_path: url,
             client,
             user_agent: Some("Sirius/0.0.1/rust".to_owned()),
+            epoch: TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS,
+            max_deadline: MAX_DEADLINE_WINDOW,
+        }
+    }
+
+    pub(crate) fn with_network_properties(&self, properties: &NetworkProperties) -> Self {
+        ApiClient {
+            base_path: self.base_path.clone(),
+            client: self.client.clone(),
+            user_agent: self.user_agent.clone(),
+            epoch: properties.epoch,
+            max_deadline: properties.max_deadline,
         }
     }
 
-    fn new_with_client(client: ReqwestClient, url: String) -> Self {
+    pub(crate) fn new_with_client(client: ReqwestClient, url: String) -> Self {
         ApiClient {
             base_path: url,
             client,
             user_agent: Some("Sirius/0.0.1/rust".to_owned()),
+            epoch: TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS,
+            max_deadline: MAX_DEADLINE_WINDOW,
         }
     }
 }
@@ -0,0 +1,227 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use crate::{
+    api::{routes::block_routes_api::BlockRoutes, SiriusClient},
+    blockchain::{BlockRangeOptions, BlockWithTransactions, BLOCK_RANGE_CONCURRENCY},
+    error::Error,
+    errors_const::ERR_INDEXER_INVALID_BATCH_SIZE,
+    models::Result,
+};
+
+use super::{IndexedBlock, IndexedEntry, IndexedTransaction, IndexedTransfer, IndexerStorage};
+
+/// The default number of blocks fetched per indexing round.
+pub const INDEXER_BATCH_SIZE: u64 = 500;
+
+/// What a call to [ChainIndexer::sync] did.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexerProgress {
+    /// The number of blocks stored.
+    pub indexed_blocks: u64,
+    /// The number of blocks removed because the chain reorganized.
+    pub rolled_back_blocks: u64,
+    /// The height of the last indexed block, 0 when nothing was indexed yet.
+    pub height: u64,
+}
+
+impl core::fmt::Display for IndexerProgress {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+/// Follows the chain from a start height, storing blocks, their transactions and the
+/// transfers of every address into an [IndexerStorage].
+///
+/// Indexing resumes after the last block found in the storage, so a restarted indexer
+/// picks up where it stopped. Every block is checked to follow the previous one; when
+/// the chain reorganized, the indexed blocks that are no longer part of it are rolled
+/// back and indexed again.
+///
+/// # Example
+/// ```
+///
+///use xpx_chain_sdk::api::SiriusClient;
+///use xpx_chain_sdk::indexer::{ChainIndexer, MemoryStorage};
+///
+///#[tokio::main]
+///async fn main() {
+/// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+/// let client = SiriusClient::new_from_urls(&node_url).await.unwrap();
+///
+///    let mut indexer = ChainIndexer::new(&client, MemoryStorage::new(), 1);
+///
+///    match indexer.sync().await {
+///        Ok(progress) => println!("{}", progress),
+///        Err(err) => eprintln!("{:?}", err),
+///    }
+///}
+/// ```
+pub struct ChainIndexer<S> {
+    block_api: BlockRoutes,
+    storage: S,
+    start_height: u64,
+    batch_size: u64,
+    concurrency: usize,
+}
+
+impl<S: IndexerStorage> ChainIndexer<S> {
+    /// Creates an indexer storing into [storage], starting at [start_height] when the
+    /// storage is empty.
+    pub fn new(client: &SiriusClient, storage: S, start_height: u64) -> Self {
+        ChainIndexer {
+            block_api: *client.block_api(),
+            storage,
+            start_height: start_height.max(1),
+            batch_size: INDEXER_BATCH_SIZE,
+            concurrency: BLOCK_RANGE_CONCURRENCY,
+        }
+    }
+
+    /// Sets the number of blocks fetched per indexing round, and how many pages of
+    /// blocks are fetched at the same time.
+    pub fn with_batch_size(mut self, batch_size: u64, concurrency: usize) -> Result<Self> {
+        if batch_size == 0 {
+            return Err(Error::from(ERR_INDEXER_INVALID_BATCH_SIZE));
+        }
+
+        BlockRangeOptions::new(concurrency, true)?;
+
+        self.batch_size = batch_size;
+        self.concurrency = concurrency;
+        Ok(self)
+    }
+
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    pub fn into_storage(self) -> S {
+        self.storage
+    }
+
+    /// The height of the next block to index.
+    pub fn next_height(&self) -> Result<u64> {
+        Ok(match self.storage.last_block()? {
+            Some(last) => last.height + 1,
+            None => self.start_height,
+        })
+    }
+
+    /// Indexes every block up to the current chain height.
+    ///
+    /// Call it again, on a timer or from a new block handler of the websocket client,
+    /// to keep following the chain.
+    pub async fn sync(&mut self) -> Result<IndexerProgress> {
+        let chain_height = self.block_api.clone().get_chain_height().await?;
+
+        let mut progress = IndexerProgress::default();
+
+        'rounds: loop {
+            let next = self.next_height()?;
+            if next > chain_height {
+                break;
+            }
+
+            let end = chain_height.min(next + self.batch_size - 1);
+
+            let blocks = self
+                .block_api
+                .clone()
+                .get_blocks(next..=end, BlockRangeOptions::new(self.concurrency, true)?)
+                .await?;
+
+            let mut last = self.storage.last_block()?;
+            for block in blocks.into_iter() {
+                if let Some(ref last) = last {
+                    if block.block.previous_block_hash.to_string() != last.hash {
+                        progress.rolled_back_blocks += self.rollback_fork().await?;
+                        continue 'rounds;
+                    }
+                }
+
+                let entry = self.entry(block).await?;
+                last = Some(entry.block.clone());
+
+                self.storage.store(entry)?;
+                progress.indexed_blocks += 1;
+            }
+        }
+
+        progress.height = self
+            .storage
+            .last_block()?
+            .map(|last| last.height)
+            .unwrap_or_default();
+
+        Ok(progress)
+    }
+
+    /// Rolls back the indexed blocks that are no longer part of the chain, returning how
+    /// many were removed.
+    async fn rollback_fork(&mut self) -> Result<u64> {
+        let mut rolled_back = 0;
+
+        while let Some(last) = self.storage.last_block()? {
+            let block = self
+                .block_api
+                .clone()
+                .get_block_by_height(last.height)
+                .await?;
+
+            if block.hash.to_string() == last.hash {
+                break;
+            }
+
+            self.storage.rollback(last.height - 1)?;
+            rolled_back += 1;
+        }
+
+        Ok(rolled_back)
+    }
+
+    /// Builds the entry of [block], resolving the namespace aliases of its transfers.
+    async fn entry(&self, block: BlockWithTransactions) -> Result<IndexedEntry> {
+        let transactions = block.transactions.unwrap_or_default();
+
+        let resolutions = if transactions.is_empty() {
+            None
+        } else {
+            Some(
+                self.block_api
+                    .clone()
+                    .get_block_resolutions(*block.block.height)
+                    .await?,
+            )
+        };
+
+        let mut indexed_transactions = vec![];
+        let mut transfers = vec![];
+        for transaction in transactions.into_iter() {
+            let resolved = match resolutions {
+                Some(ref resolutions) => resolutions
+                    .resolve_transaction(transaction.as_ref())
+                    .unwrap_or(transaction),
+                None => transaction,
+            };
+
+            indexed_transactions.push(IndexedTransaction::new(resolved.as_ref()));
+            transfers.extend(IndexedTransfer::from_transaction(resolved.as_ref()));
+        }
+
+        Ok(IndexedEntry {
+            block: IndexedBlock::from(&block.block),
+            transactions: indexed_transactions,
+            transfers,
+        })
+    }
+}
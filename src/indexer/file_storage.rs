@@ -0,0 +1,137 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use ::std::{
+    collections::BTreeSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{account::Address, Result};
+
+use super::{
+    ensure_next_block, IndexedBlock, IndexedEntry, IndexedTransaction, IndexedTransfer,
+    IndexerStorage,
+};
+
+const ENTRY_EXTENSION: &str = "json";
+
+/// An [IndexerStorage] that keeps every indexed block in its own JSON file of a directory.
+///
+/// Files are written to a temporary file first and then renamed, so a block is either
+/// stored completely or not at all, and the indexer can resume after a restart from
+/// what is on disk.
+#[derive(Debug, Clone)]
+pub struct FileStorage {
+    directory: PathBuf,
+    heights: BTreeSet<u64>,
+}
+
+impl FileStorage {
+    /// Opens the storage in [directory], creating it if needed.
+    pub fn open<P: AsRef<Path>>(directory: P) -> Result<Self> {
+        let directory = directory.as_ref().to_path_buf();
+
+        fs::create_dir_all(&directory)?;
+
+        let mut heights = BTreeSet::new();
+        for file in fs::read_dir(&directory)? {
+            let path = file?.path();
+
+            if path.extension().and_then(|extension| extension.to_str()) != Some(ENTRY_EXTENSION) {
+                continue;
+            }
+
+            if let Some(height) = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.parse::<u64>().ok())
+            {
+                heights.insert(height);
+            }
+        }
+
+        Ok(FileStorage { directory, heights })
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    fn entry_path(&self, height: u64) -> PathBuf {
+        self.directory
+            .join(format!("{:020}.{}", height, ENTRY_EXTENSION))
+    }
+
+    fn read_entry(&self, height: u64) -> Result<Option<IndexedEntry>> {
+        if !self.heights.contains(&height) {
+            return Ok(None);
+        }
+
+        let data = fs::read(self.entry_path(height))?;
+        Ok(Some(serde_json::from_slice(&data)?))
+    }
+}
+
+impl IndexerStorage for FileStorage {
+    fn last_block(&self) -> Result<Option<IndexedBlock>> {
+        match self.heights.iter().next_back() {
+            Some(height) => self.block(*height),
+            None => Ok(None),
+        }
+    }
+
+    fn block(&self, height: u64) -> Result<Option<IndexedBlock>> {
+        Ok(self.read_entry(height)?.map(|entry| entry.block))
+    }
+
+    fn transactions(&self, height: u64) -> Result<Vec<IndexedTransaction>> {
+        Ok(self
+            .read_entry(height)?
+            .map(|entry| entry.transactions)
+            .unwrap_or_default())
+    }
+
+    /// Reads every stored block, the file storage keeps no index by address.
+    fn transfers(&self, address: &Address) -> Result<Vec<IndexedTransfer>> {
+        let mut transfers = vec![];
+        for height in self.heights.iter() {
+            if let Some(entry) = self.read_entry(*height)? {
+                transfers.extend(
+                    entry
+                        .transfers
+                        .into_iter()
+                        .filter(|transfer| transfer.is_for(address)),
+                );
+            }
+        }
+        Ok(transfers)
+    }
+
+    fn store(&mut self, entry: IndexedEntry) -> Result<()> {
+        ensure_next_block(self.last_block()?.as_ref(), &entry)?;
+
+        let height = entry.block.height;
+        let path = self.entry_path(height);
+        let temporary = path.with_extension("tmp");
+
+        fs::write(&temporary, serde_json::to_vec(&entry)?)?;
+        fs::rename(&temporary, &path)?;
+
+        self.heights.insert(height);
+        Ok(())
+    }
+
+    fn rollback(&mut self, height: u64) -> Result<()> {
+        let removed: Vec<u64> = self.heights.range(height + 1..).rev().copied().collect();
+
+        for height in removed.into_iter() {
+            fs::remove_file(self.entry_path(height))?;
+            self.heights.remove(&height);
+        }
+        Ok(())
+    }
+}
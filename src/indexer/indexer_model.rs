@@ -0,0 +1,224 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use crate::{
+    account::Address,
+    blockchain::BlockInfo,
+    transaction::{AggregateTransaction, Transaction, TransferTransaction},
+};
+
+/// A block as stored by the indexer.
+///
+/// Hashes, accounts and addresses are kept as hexadecimal or base32 strings, so a stored
+/// block reads back the same from any storage.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedBlock {
+    pub height: u64,
+    pub hash: String,
+    pub previous_block_hash: String,
+    /// The block timestamp, in milliseconds since the Unix epoch, so it does not depend on
+    /// the epoch of the network.
+    pub timestamp: u64,
+    pub signer: String,
+    pub num_transactions: u64,
+}
+
+impl From<&BlockInfo> for IndexedBlock {
+    fn from(block: &BlockInfo) -> Self {
+        IndexedBlock {
+            height: *block.height,
+            hash: block.hash.to_string(),
+            previous_block_hash: block.previous_block_hash.to_string(),
+            timestamp: *block
+                .timestamp
+                .to_blockchain_timestamp_with_epoch(0)
+                .to_uint64(),
+            signer: block.signer.public_key_string(),
+            num_transactions: block.num_transactions,
+        }
+    }
+}
+
+impl core::fmt::Display for IndexedBlock {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+/// A confirmed transaction as stored by the indexer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedTransaction {
+    pub height: u64,
+    /// The index of the transaction within its block.
+    pub index: u32,
+    pub hash: String,
+    pub transaction_type: u16,
+    pub signer: String,
+    /// The transaction as returned by [Transaction::as_value].
+    pub transaction: serde_json::Value,
+}
+
+impl IndexedTransaction {
+    pub fn new(transaction: &dyn Transaction) -> Self {
+        let abs_transaction = transaction.abs_transaction();
+
+        IndexedTransaction {
+            height: *transaction.height(),
+            index: abs_transaction
+                .transaction_info
+                .as_ref()
+                .map(|info| info.index)
+                .unwrap_or_default(),
+            hash: transaction.transaction_hash().to_string(),
+            transaction_type: abs_transaction.transaction_type.into(),
+            signer: abs_transaction.signer.public_key_string(),
+            transaction: transaction.as_value(),
+        }
+    }
+}
+
+impl core::fmt::Display for IndexedTransaction {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+/// Whether a transfer was sent or received by the address it is indexed for.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TransferDirection {
+    Incoming,
+    Outgoing,
+}
+
+/// An amount of a mosaic moved by a transfer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedAmount {
+    /// The mosaic id, or the namespace id when the alias could not be resolved, in hex.
+    pub asset_id: String,
+    pub amount: u64,
+}
+
+/// A transfer, indexed for one of the two addresses taking part in it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedTransfer {
+    /// The address the transfer is indexed for.
+    pub address: String,
+    pub direction: TransferDirection,
+    /// The other address taking part in the transfer.
+    pub counterparty: String,
+    pub height: u64,
+    /// The hash of the transaction, or of the aggregate containing it.
+    pub transaction_hash: String,
+    pub mosaics: Vec<IndexedAmount>,
+}
+
+impl IndexedTransfer {
+    /// Returns the outgoing and incoming transfers of [transaction], including the ones
+    /// inside an aggregate.
+    ///
+    /// Namespace aliases should be resolved beforehand, an unresolved recipient alias is
+    /// indexed as it is.
+    pub fn from_transaction(transaction: &dyn Transaction) -> Vec<IndexedTransfer> {
+        let height = *transaction.height();
+        let hash = transaction.transaction_hash().to_string();
+
+        let mut transfers = vec![];
+
+        if let Some(aggregate) = transaction.try_downcast_ref::<AggregateTransaction>() {
+            for inner in aggregate.inner_transactions.iter() {
+                transfers.extend(Self::from_inner(inner.as_ref(), height, &hash));
+            }
+        } else {
+            transfers.extend(Self::from_inner(transaction, height, &hash));
+        }
+
+        transfers
+    }
+
+    fn from_inner(transaction: &dyn Transaction, height: u64, hash: &str) -> Vec<IndexedTransfer> {
+        let transfer = match transaction.try_downcast_ref::<TransferTransaction>() {
+            Some(transfer) => transfer,
+            None => return vec![],
+        };
+
+        let sender = transfer.abs_transaction.signer.address.address_string();
+        let recipient = transfer.recipient.address_string();
+
+        let mosaics: Vec<IndexedAmount> = transfer
+            .mosaics
+            .iter()
+            .map(|mosaic| IndexedAmount {
+                asset_id: mosaic.asset_id.to_hex(),
+                amount: *mosaic.amount,
+            })
+            .collect();
+
+        vec![
+            IndexedTransfer {
+                address: sender.clone(),
+                direction: TransferDirection::Outgoing,
+                counterparty: recipient.clone(),
+                height,
+                transaction_hash: hash.to_string(),
+                mosaics: mosaics.clone(),
+            },
+            IndexedTransfer {
+                address: recipient,
+                direction: TransferDirection::Incoming,
+                counterparty: sender,
+                height,
+                transaction_hash: hash.to_string(),
+                mosaics,
+            },
+        ]
+    }
+
+    /// Returns `true` if the transfer is indexed for [address].
+    pub fn is_for(&self, address: &Address) -> bool {
+        self.address == address.address_string()
+    }
+}
+
+impl core::fmt::Display for IndexedTransfer {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+/// Everything the indexer stores for one block.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedEntry {
+    pub block: IndexedBlock,
+    pub transactions: Vec<IndexedTransaction>,
+    pub transfers: Vec<IndexedTransfer>,
+}
+
+impl core::fmt::Display for IndexedEntry {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
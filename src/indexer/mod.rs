@@ -0,0 +1,15 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+pub use self::chain_indexer::*;
+pub use self::file_storage::*;
+pub use self::indexer_model::*;
+pub use self::storage::*;
+
+mod chain_indexer;
+mod file_storage;
+mod indexer_model;
+mod storage;
@@ -0,0 +1,113 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use ::std::collections::BTreeMap;
+
+use crate::{account::Address, errors_const, Result};
+
+use super::{IndexedBlock, IndexedEntry, IndexedTransaction, IndexedTransfer};
+
+/// Where the [ChainIndexer](super::ChainIndexer) keeps what it indexed.
+///
+/// Blocks are stored in order of height without gaps, the storage only ever grows by
+/// one block at the end or shrinks from the end after a rollback.
+pub trait IndexerStorage {
+    /// The last indexed block, `None` when nothing was indexed yet.
+    fn last_block(&self) -> Result<Option<IndexedBlock>>;
+
+    /// The indexed block at [height].
+    fn block(&self, height: u64) -> Result<Option<IndexedBlock>>;
+
+    /// The indexed transactions of the block at [height].
+    fn transactions(&self, height: u64) -> Result<Vec<IndexedTransaction>>;
+
+    /// Every indexed transfer sent or received by [address], in order of height.
+    fn transfers(&self, address: &Address) -> Result<Vec<IndexedTransfer>>;
+
+    /// Stores the [entry] of the block following the last indexed one.
+    fn store(&mut self, entry: IndexedEntry) -> Result<()>;
+
+    /// Removes every indexed block above [height].
+    fn rollback(&mut self, height: u64) -> Result<()>;
+}
+
+/// Checks [entry] follows the [last] indexed block.
+pub(crate) fn ensure_next_block(last: Option<&IndexedBlock>, entry: &IndexedEntry) -> Result<()> {
+    if let Some(last) = last {
+        ensure!(
+            entry.block.height == last.height + 1 && entry.block.previous_block_hash == last.hash,
+            errors_const::ERR_INDEXER_NOT_NEXT_BLOCK
+        );
+    }
+    Ok(())
+}
+
+/// An [IndexerStorage] that keeps everything in memory.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStorage {
+    entries: BTreeMap<u64, IndexedEntry>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl IndexerStorage for MemoryStorage {
+    fn last_block(&self) -> Result<Option<IndexedBlock>> {
+        Ok(self
+            .entries
+            .values()
+            .next_back()
+            .map(|entry| entry.block.clone()))
+    }
+
+    fn block(&self, height: u64) -> Result<Option<IndexedBlock>> {
+        Ok(self.entries.get(&height).map(|entry| entry.block.clone()))
+    }
+
+    fn transactions(&self, height: u64) -> Result<Vec<IndexedTransaction>> {
+        Ok(self
+            .entries
+            .get(&height)
+            .map(|entry| entry.transactions.clone())
+            .unwrap_or_default())
+    }
+
+    fn transfers(&self, address: &Address) -> Result<Vec<IndexedTransfer>> {
+        Ok(self
+            .entries
+            .values()
+            .flat_map(|entry| entry.transfers.iter())
+            .filter(|transfer| transfer.is_for(address))
+            .cloned()
+            .collect())
+    }
+
+    fn store(&mut self, entry: IndexedEntry) -> Result<()> {
+        ensure_next_block(
+            self.entries.values().next_back().map(|entry| &entry.block),
+            &entry,
+        )?;
+
+        self.entries.insert(entry.block.height, entry);
+        Ok(())
+    }
+
+    fn rollback(&mut self, height: u64) -> Result<()> {
+        self.entries.split_off(&(height + 1));
+        Ok(())
+    }
+}
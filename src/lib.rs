@@ -19,6 +19,7 @@ pub use self::models::*;
 
 pub mod api;
 mod helpers;
+pub mod indexer;
 mod models;
 pub mod websocket;
 
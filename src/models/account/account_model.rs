@@ -25,7 +25,7 @@ use crate::{
     Result,
 };
 
-use super::{Address, PublicAccount};
+use super::{Address, PublicAccount, SignedMessage};
 
 pub type AccountId = String;
 
@@ -130,13 +130,32 @@ impl Account {
         SecureMessage::create(self, &recipient_public_account, message)
     }
 
+    /// Creates an encrypted message of raw bytes from this account to the [recipientPublicAccount].
+    pub fn encrypt_bytes(
+        &self,
+        recipient_public_account: &PublicAccount,
+        message: &[u8],
+    ) -> Result<SecureMessage> {
+        SecureMessage::from_bytes(self, &recipient_public_account, message)
+    }
+
     /// Decrypts an encrypted message received by this account from [senderPublicAccount].
     pub fn decrypt_message(
         &self,
         sender_public_account: &PublicAccount,
         secure_message: SecureMessage,
     ) -> Result<PlainMessage> {
-        secure_message.decrypt(self, sender_public_account)
+        Ok(secure_message.decrypt(self, sender_public_account)?)
+    }
+
+    /// Decrypts the raw bytes of an encrypted message received by this account
+    /// from [senderPublicAccount].
+    pub fn decrypt_bytes(
+        &self,
+        sender_public_account: &PublicAccount,
+        secure_message: &SecureMessage,
+    ) -> Result<Vec<u8>> {
+        Ok(secure_message.decrypt_to_bytes(self, sender_public_account)?)
     }
 
     /// Signs 'Transaction'.
@@ -161,6 +180,23 @@ impl Account {
         hex_encode(sig)
     }
 
+    /// Signs an off-chain message using the domain-separated [`SignedMessage`] format.
+    ///
+    /// The signature is not bound to any network, use `sign_message_for_network` for
+    /// login challenges and proofs of ownership that must not be replayed on another chain.
+    pub fn sign_message(&self, data: &[u8]) -> Result<SignedMessage> {
+        SignedMessage::create(self, data, None, None)
+    }
+
+    /// Signs an off-chain message bound to this account's network and the given generation hash.
+    pub fn sign_message_for_network(
+        &self,
+        data: &[u8],
+        generation_hash: HashValue,
+    ) -> Result<SignedMessage> {
+        SignedMessage::create(self, data, Some(self.network_type()), Some(generation_hash))
+    }
+
     /// Sign transaction with cosignatories creating a new signed_transaction.
     pub fn sign_with_cosignatories(
         &self,
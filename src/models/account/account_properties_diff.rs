@@ -0,0 +1,362 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use crate::{
+    models::{
+        errors_const,
+        mosaic::MosaicId,
+        network::NetworkType,
+        transaction::{
+            AccountPropertiesAddressTransaction, AccountPropertiesEntityTypeTransaction,
+            AccountPropertiesMosaicTransaction, Deadline, Transaction, TransactionType,
+        },
+    },
+    Result,
+};
+
+use super::{
+    AccountProperties, AccountPropertiesAddressModification,
+    AccountPropertiesEntityTypeModification, AccountPropertiesModificationType,
+    AccountPropertiesMosaicModification, AccountPropertyType, Address,
+};
+
+use AccountPropertiesModificationType::{AddProperty, RemoveProperty};
+
+/// The allow and block lists an account should end up with.
+///
+/// For every kind of property, at most one of the allow and block lists can be non-empty.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountPropertiesConfig {
+    pub allowed_addresses: Vec<Address>,
+    pub blocked_addresses: Vec<Address>,
+    pub allowed_mosaic_ids: Vec<MosaicId>,
+    pub blocked_mosaic_ids: Vec<MosaicId>,
+    pub allowed_entity_types: Vec<TransactionType>,
+    pub blocked_entity_types: Vec<TransactionType>,
+}
+
+impl AccountPropertiesConfig {
+    /// Checks that no kind of property is both allowed and blocked, and that no
+    /// property is listed twice.
+    pub fn validate(&self) -> Result<()> {
+        validate_lists(&self.allowed_addresses, &self.blocked_addresses)?;
+        validate_lists(&self.allowed_mosaic_ids, &self.blocked_mosaic_ids)?;
+        validate_lists(&self.allowed_entity_types, &self.blocked_entity_types)
+    }
+}
+
+impl From<&AccountProperties> for AccountPropertiesConfig {
+    fn from(properties: &AccountProperties) -> Self {
+        AccountPropertiesConfig {
+            allowed_addresses: properties.allowed_addresses.clone(),
+            blocked_addresses: properties.blocked_addresses.clone(),
+            allowed_mosaic_ids: properties.allowed_mosaic_id.clone(),
+            blocked_mosaic_ids: properties.blocked_mosaic_id.clone(),
+            allowed_entity_types: properties.allowed_entity_types.clone(),
+            blocked_entity_types: properties.blocked_entity_types.clone(),
+        }
+    }
+}
+
+fn validate_lists<T: PartialEq>(allowed: &[T], blocked: &[T]) -> Result<()> {
+    ensure!(
+        allowed.is_empty() || blocked.is_empty(),
+        errors_const::ERR_ACCOUNT_PROPERTY_ALLOW_AND_BLOCK
+    );
+
+    for list in [allowed, blocked].iter() {
+        for (index, item) in list.iter().enumerate() {
+            ensure!(
+                !list[..index].contains(item),
+                errors_const::ERR_ACCOUNT_PROPERTY_DUPLICATE
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Applies [modifications] to the [current] list, failing on the modifications the
+/// chain would reject. The [counterpart] is the opposite list of the same kind, which
+/// must be empty once the current list is not.
+fn apply_modifications<T: PartialEq + Copy>(
+    current: &mut Vec<T>,
+    counterpart: &[T],
+    modifications: &[(AccountPropertiesModificationType, T)],
+) -> Result<()> {
+    for (modification_type, item) in modifications.iter() {
+        match modification_type {
+            AddProperty => {
+                ensure!(
+                    !current.contains(item),
+                    errors_const::ERR_ACCOUNT_PROPERTY_ALREADY_PRESENT
+                );
+                current.push(*item);
+            }
+            RemoveProperty => {
+                let position = current.iter().position(|present| present == item);
+                match position {
+                    Some(position) => {
+                        current.remove(position);
+                    }
+                    None => bail!(errors_const::ERR_ACCOUNT_PROPERTY_NOT_PRESENT),
+                }
+            }
+            _ => bail!(errors_const::ERR_INVALID_ACCOUNT_PROPERTY_MODIFICATION),
+        }
+    }
+
+    ensure!(
+        current.is_empty() || counterpart.is_empty(),
+        errors_const::ERR_ACCOUNT_PROPERTY_ALLOW_AND_BLOCK
+    );
+
+    Ok(())
+}
+
+/// Returns the modifications that turn [current] into [desired], removals first.
+fn list_modifications<T: PartialEq + Copy>(
+    current: &[T],
+    desired: &[T],
+) -> Vec<(AccountPropertiesModificationType, T)> {
+    let removals = current
+        .iter()
+        .filter(|item| !desired.contains(item))
+        .map(|item| (RemoveProperty, *item));
+
+    let additions = desired
+        .iter()
+        .filter(|item| !current.contains(item))
+        .map(|item| (AddProperty, *item));
+
+    removals.chain(additions).collect()
+}
+
+/// The modifications of both lists of one kind of property, in the order they can be
+/// applied: the list that ends up empty is changed first, so the account never allows
+/// and blocks the same kind of property at once.
+fn kind_modifications<T: PartialEq + Copy>(
+    allow_type: AccountPropertyType,
+    block_type: AccountPropertyType,
+    current: (&[T], &[T]),
+    desired: (&[T], &[T]),
+) -> Vec<(
+    AccountPropertyType,
+    Vec<(AccountPropertiesModificationType, T)>,
+)> {
+    let allow = (allow_type, list_modifications(current.0, desired.0));
+    let block = (block_type, list_modifications(current.1, desired.1));
+
+    let ordered = if desired.0.is_empty() {
+        vec![allow, block]
+    } else {
+        vec![block, allow]
+    };
+
+    ordered
+        .into_iter()
+        .filter(|(_, modifications)| !modifications.is_empty())
+        .collect()
+}
+
+impl AccountProperties {
+    fn address_lists(
+        &mut self,
+        property_type: AccountPropertyType,
+    ) -> Result<(&mut Vec<Address>, &[Address])> {
+        match property_type {
+            AccountPropertyType::AllowAddress => {
+                Ok((&mut self.allowed_addresses, &self.blocked_addresses))
+            }
+            AccountPropertyType::BlockAddress => {
+                Ok((&mut self.blocked_addresses, &self.allowed_addresses))
+            }
+            _ => bail!(errors_const::ERR_INVALID_ACCOUNT_PROPERTY_TYPE),
+        }
+    }
+
+    fn mosaic_lists(
+        &mut self,
+        property_type: AccountPropertyType,
+    ) -> Result<(&mut Vec<MosaicId>, &[MosaicId])> {
+        match property_type {
+            AccountPropertyType::AllowMosaic => {
+                Ok((&mut self.allowed_mosaic_id, &self.blocked_mosaic_id))
+            }
+            AccountPropertyType::BlockMosaic => {
+                Ok((&mut self.blocked_mosaic_id, &self.allowed_mosaic_id))
+            }
+            _ => bail!(errors_const::ERR_INVALID_ACCOUNT_PROPERTY_TYPE),
+        }
+    }
+
+    fn entity_type_lists(
+        &mut self,
+        property_type: AccountPropertyType,
+    ) -> Result<(&mut Vec<TransactionType>, &[TransactionType])> {
+        match property_type {
+            AccountPropertyType::AllowTransaction => {
+                Ok((&mut self.allowed_entity_types, &self.blocked_entity_types))
+            }
+            AccountPropertyType::BlockTransaction => {
+                Ok((&mut self.blocked_entity_types, &self.allowed_entity_types))
+            }
+            _ => bail!(errors_const::ERR_INVALID_ACCOUNT_PROPERTY_TYPE),
+        }
+    }
+
+    /// Checks that the address [modifications] of [property_type] can be applied to
+    /// these properties, and applies them.
+    pub fn apply_address_modifications(
+        &mut self,
+        property_type: AccountPropertyType,
+        modifications: &[AccountPropertiesAddressModification],
+    ) -> Result<()> {
+        let modifications: Vec<_> = modifications
+            .iter()
+            .map(|modification| (modification.modification_type, modification.address))
+            .collect();
+
+        let (current, counterpart) = self.address_lists(property_type)?;
+        apply_modifications(current, counterpart, &modifications)
+    }
+
+    /// Checks that the mosaic [modifications] of [property_type] can be applied to
+    /// these properties, and applies them.
+    pub fn apply_mosaic_modifications(
+        &mut self,
+        property_type: AccountPropertyType,
+        modifications: &[AccountPropertiesMosaicModification],
+    ) -> Result<()> {
+        let modifications: Vec<_> = modifications
+            .iter()
+            .map(|modification| {
+                (
+                    modification.modification_type,
+                    MosaicId::from(modification.asset_id.to_u64()),
+                )
+            })
+            .collect();
+
+        let (current, counterpart) = self.mosaic_lists(property_type)?;
+        apply_modifications(current, counterpart, &modifications)
+    }
+
+    /// Checks that the entity type [modifications] of [property_type] can be applied to
+    /// these properties, and applies them.
+    pub fn apply_entity_type_modifications(
+        &mut self,
+        property_type: AccountPropertyType,
+        modifications: &[AccountPropertiesEntityTypeModification],
+    ) -> Result<()> {
+        let modifications: Vec<_> = modifications
+            .iter()
+            .map(|modification| {
+                (
+                    modification.modification_type,
+                    modification.transaction_type,
+                )
+            })
+            .collect();
+
+        let (current, counterpart) = self.entity_type_lists(property_type)?;
+        apply_modifications(current, counterpart, &modifications)
+    }
+
+    /// Creates the minimal transactions that turn these properties into [desired],
+    /// in the order they must be announced. No transaction is returned when the
+    /// properties already match.
+    ///
+    /// Fails for configurations the chain would reject.
+    pub fn diff(
+        &self,
+        desired: &AccountPropertiesConfig,
+        deadline: Deadline,
+        network_type: NetworkType,
+    ) -> Result<Vec<Box<dyn Transaction>>> {
+        desired.validate()?;
+
+        // Applied to a copy, so every transaction is checked against the state the
+        // previous ones leave.
+        let mut state = self.clone();
+        let mut transactions: Vec<Box<dyn Transaction>> = vec![];
+
+        for (property_type, modifications) in kind_modifications(
+            AccountPropertyType::AllowAddress,
+            AccountPropertyType::BlockAddress,
+            (&self.allowed_addresses, &self.blocked_addresses),
+            (&desired.allowed_addresses, &desired.blocked_addresses),
+        ) {
+            let modifications: Vec<_> = modifications
+                .into_iter()
+                .map(|(modification_type, address)| {
+                    AccountPropertiesAddressModification::new(modification_type, address)
+                })
+                .collect();
+
+            state.apply_address_modifications(property_type, &modifications)?;
+
+            transactions.push(Box::new(AccountPropertiesAddressTransaction::new(
+                deadline,
+                property_type,
+                modifications,
+                network_type,
+            )?));
+        }
+
+        for (property_type, modifications) in kind_modifications(
+            AccountPropertyType::AllowMosaic,
+            AccountPropertyType::BlockMosaic,
+            (&self.allowed_mosaic_id, &self.blocked_mosaic_id),
+            (&desired.allowed_mosaic_ids, &desired.blocked_mosaic_ids),
+        ) {
+            let modifications: Vec<_> = modifications
+                .into_iter()
+                .map(|(modification_type, mosaic_id)| {
+                    AccountPropertiesMosaicModification::new(modification_type, mosaic_id)
+                })
+                .collect();
+
+            state.apply_mosaic_modifications(property_type, &modifications)?;
+
+            transactions.push(Box::new(AccountPropertiesMosaicTransaction::new(
+                deadline,
+                property_type,
+                modifications,
+                network_type,
+            )?));
+        }
+
+        for (property_type, modifications) in kind_modifications(
+            AccountPropertyType::AllowTransaction,
+            AccountPropertyType::BlockTransaction,
+            (&self.allowed_entity_types, &self.blocked_entity_types),
+            (&desired.allowed_entity_types, &desired.blocked_entity_types),
+        ) {
+            let modifications: Vec<_> = modifications
+                .into_iter()
+                .map(|(modification_type, transaction_type)| {
+                    AccountPropertiesEntityTypeModification::new(
+                        modification_type,
+                        transaction_type,
+                    )
+                })
+                .collect();
+
+            state.apply_entity_type_modifications(property_type, &modifications)?;
+
+            transactions.push(Box::new(AccountPropertiesEntityTypeTransaction::new(
+                deadline,
+                property_type,
+                modifications,
+                network_type,
+            )?));
+        }
+
+        Ok(transactions)
+    }
+}
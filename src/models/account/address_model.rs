@@ -14,8 +14,8 @@ use {
 };
 
 use crate::{
-    helpers::{hex_decode, is_hex},
-    models::{errors_const, network::*},
+    helpers::{array_u8_to_u64, hex_decode, is_hex},
+    models::{errors_const, namespace::NamespaceId, network::*, Uint64},
     Result,
 };
 
@@ -181,6 +181,22 @@ impl Address {
     pub fn network_type(&self) -> NetworkType {
         self.network_type
     }
+
+    /// Returns true if this address is a namespace alias rather than an account address.
+    pub fn is_namespace_alias(&self) -> bool {
+        self.address[0] & 0x01 == 0x01
+    }
+
+    /// The namespace this address is an alias of, or `None` for an account address.
+    pub fn to_namespace_id(&self) -> Option<NamespaceId> {
+        if !self.is_namespace_alias() {
+            return None;
+        }
+
+        Some(NamespaceId::from(Uint64::new(array_u8_to_u64(
+            &self.address[1..9],
+        ))))
+    }
 }
 
 impl fmt::Display for Address {
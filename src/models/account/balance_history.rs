@@ -0,0 +1,552 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use ::std::{
+    collections::{BTreeMap, HashMap},
+    convert::TryFrom,
+};
+
+use crate::models::{
+    exchange::OfferType,
+    mosaic::{Mosaic, MosaicId, MosaicNames},
+    receipt::{BalanceReceipt, BalanceReceiptKind, RECEIPT_TYPE_OFFER_REMOVED},
+    transaction::{
+        calculate_max_fee, AddExchangeOfferTransaction, AggregateTransaction,
+        ExchangeOfferTransaction, FeeMultiplier, HashValue, Height, Timestamp, Transaction,
+        TransferTransaction,
+    },
+    AssetId, AssetIdType, Uint64,
+};
+
+use super::{AccountInfo, Address};
+
+/// What caused the balance of an account to change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BalanceChangeKind {
+    TransferIn,
+    TransferOut,
+    /// The fee of a transaction signed by the account.
+    Fee,
+    /// Exchange offers created or filled.
+    Exchange,
+    /// Hash or secret lock funds locked, released or expired.
+    Lock,
+    /// Fees collected by harvesting a block.
+    Harvest,
+    /// Mosaic or namespace rental fees, and mosaic levies.
+    Rental,
+    Other,
+}
+
+impl From<BalanceReceiptKind> for BalanceChangeKind {
+    fn from(kind: BalanceReceiptKind) -> Self {
+        match kind {
+            BalanceReceiptKind::Harvest => BalanceChangeKind::Harvest,
+            BalanceReceiptKind::Lock => BalanceChangeKind::Lock,
+            BalanceReceiptKind::Mosaic => BalanceChangeKind::Rental,
+            BalanceReceiptKind::Exchange => BalanceChangeKind::Exchange,
+            BalanceReceiptKind::Other => BalanceChangeKind::Other,
+        }
+    }
+}
+
+/// A change of the balance of one mosaic of an account.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceChange {
+    pub height: Height,
+    pub mosaic_id: MosaicId,
+    pub delta: i64,
+    pub kind: BalanceChangeKind,
+    /// The transaction causing the change, `None` for changes coming from receipts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_hash: Option<HashValue>,
+}
+
+impl BalanceChange {
+    fn new(
+        height: Height,
+        mosaic_id: MosaicId,
+        delta: i128,
+        kind: BalanceChangeKind,
+        transaction_hash: Option<HashValue>,
+    ) -> Self {
+        BalanceChange {
+            height,
+            mosaic_id,
+            delta: i64::try_from(delta).unwrap_or(if delta < 0 { i64::MIN } else { i64::MAX }),
+            kind,
+            transaction_hash,
+        }
+    }
+}
+
+impl core::fmt::Display for BalanceChange {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+pub(crate) fn mosaic_id_of(mosaic: &Mosaic) -> Option<MosaicId> {
+    match mosaic.asset_id.get_type() {
+        AssetIdType::MosaicIdType => Some(MosaicId::from(mosaic.asset_id.to_u64())),
+        _ => None,
+    }
+}
+
+/// The fee paid by the signer of a [transaction] confirmed in a block of [fee_multiplier],
+/// that is its size times the multiplier, bounded by its max fee.
+pub(crate) fn transaction_fee(
+    transaction: &dyn Transaction,
+    fee_multiplier: FeeMultiplier,
+) -> Uint64 {
+    let fee = calculate_max_fee(transaction.size(), fee_multiplier);
+
+    match transaction.abs_transaction().max_fee {
+        Some(max_fee) => Uint64::new((*fee).min(*max_fee)),
+        None => fee,
+    }
+}
+
+/// Returns the balance changes of [address] caused by a confirmed [transaction].
+///
+/// Namespace aliases of the transaction should be resolved beforehand, mosaics still
+/// referred to by an alias are skipped. The fee of a transaction signed by [address] is
+/// paid in [currency] at the [fee_multiplier] of its block.
+///
+/// Lock funds are taken from the balance receipts rather than from the transactions,
+/// and exchange offers created or filled from the transactions rather than from the
+/// receipts. The funds returned by removed or expired offers are only known from the
+/// receipts, see [receipt_balance_changes].
+pub fn transaction_balance_changes(
+    address: &Address,
+    transaction: &dyn Transaction,
+    fee_multiplier: FeeMultiplier,
+    currency: MosaicId,
+) -> Vec<BalanceChange> {
+    let height = transaction.height();
+    let hash = Some(transaction.transaction_hash());
+
+    let mut changes = vec![];
+
+    if transaction.abs_transaction().signer.address == *address {
+        let fee = transaction_fee(transaction, fee_multiplier);
+        if *fee > 0 {
+            changes.push(BalanceChange::new(
+                height,
+                currency,
+                -i128::from(*fee),
+                BalanceChangeKind::Fee,
+                hash,
+            ));
+        }
+    }
+
+    if let Some(aggregate) = transaction.try_downcast_ref::<AggregateTransaction>() {
+        for inner in aggregate.inner_transactions.iter() {
+            changes.extend(inner_balance_changes(
+                address,
+                inner.as_ref(),
+                height,
+                hash,
+                currency,
+            ));
+        }
+    } else {
+        changes.extend(inner_balance_changes(
+            address,
+            transaction,
+            height,
+            hash,
+            currency,
+        ));
+    }
+
+    changes
+}
+
+fn inner_balance_changes(
+    address: &Address,
+    transaction: &dyn Transaction,
+    height: Height,
+    hash: Option<HashValue>,
+    currency: MosaicId,
+) -> Vec<BalanceChange> {
+    let is_signer = transaction.abs_transaction().signer.address == *address;
+
+    let mut changes = vec![];
+    let mut push = |mosaic_id: Option<MosaicId>, delta: i128, kind: BalanceChangeKind| {
+        if let Some(mosaic_id) = mosaic_id {
+            if delta != 0 {
+                changes.push(BalanceChange::new(height, mosaic_id, delta, kind, hash));
+            }
+        }
+    };
+
+    if let Some(transfer) = transaction.try_downcast_ref::<TransferTransaction>() {
+        for mosaic in transfer.mosaics.iter() {
+            let amount = i128::from(*mosaic.amount);
+            if is_signer {
+                push(
+                    mosaic_id_of(mosaic),
+                    -amount,
+                    BalanceChangeKind::TransferOut,
+                );
+            }
+            if transfer.recipient == *address {
+                push(mosaic_id_of(mosaic), amount, BalanceChangeKind::TransferIn);
+            }
+        }
+    } else if let Some(add) = transaction.try_downcast_ref::<AddExchangeOfferTransaction>() {
+        if is_signer {
+            for add_offer in add.offers.iter() {
+                let offer = &add_offer.offer;
+                match offer.r#type {
+                    OfferType::SellOffer => push(
+                        mosaic_id_of(&offer.mosaic),
+                        -i128::from(*offer.mosaic.amount),
+                        BalanceChangeKind::Exchange,
+                    ),
+                    OfferType::BuyOffer => push(
+                        Some(currency),
+                        -i128::from(*offer.cost),
+                        BalanceChangeKind::Exchange,
+                    ),
+                    _ => {}
+                }
+            }
+        }
+    } else if let Some(exchange) = transaction.try_downcast_ref::<ExchangeOfferTransaction>() {
+        for confirmation in exchange.confirmations.iter() {
+            let offer = &confirmation.offer;
+            let amount = i128::from(*offer.mosaic.amount);
+            let cost = i128::from(*offer.cost);
+            let is_owner = confirmation.owner.address == *address;
+
+            // The type is the one of the offer of the owner, whose side was locked when
+            // the offer was added.
+            let (mosaic_delta, cost_delta) = match offer.r#type {
+                OfferType::SellOffer => (
+                    if is_signer { amount } else { 0 },
+                    if is_owner { cost } else { 0 } - if is_signer { cost } else { 0 },
+                ),
+                OfferType::BuyOffer => (
+                    if is_owner { amount } else { 0 } - if is_signer { amount } else { 0 },
+                    if is_signer { cost } else { 0 },
+                ),
+                _ => (0, 0),
+            };
+
+            push(
+                mosaic_id_of(&offer.mosaic),
+                mosaic_delta,
+                BalanceChangeKind::Exchange,
+            );
+            push(Some(currency), cost_delta, BalanceChangeKind::Exchange);
+        }
+    }
+
+    changes
+}
+
+/// Returns the balance changes of [address] caused by balance [receipts].
+///
+/// Exchange receipts are skipped, as offers created or filled are taken from the
+/// transactions, except for `RECEIPT_TYPE_OFFER_REMOVED` returning the remaining funds
+/// of removed or expired offers.
+pub fn receipt_balance_changes(
+    address: &Address,
+    receipts: &[BalanceReceipt],
+) -> Vec<BalanceChange> {
+    receipts
+        .iter()
+        .filter(|receipt| {
+            receipt.kind() != BalanceReceiptKind::Exchange
+                || receipt.receipt_type == RECEIPT_TYPE_OFFER_REMOVED
+        })
+        .map(|receipt| {
+            BalanceChange::new(
+                receipt.height,
+                receipt.mosaic_id,
+                receipt.delta_for(address),
+                receipt.kind().into(),
+                None,
+            )
+        })
+        .filter(|change| change.delta != 0)
+        .collect()
+}
+
+/// A point of the balance timeline of a mosaic.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceEntry {
+    pub height: Height,
+    /// The timestamp of the block, when it is known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<Timestamp>,
+    pub delta: i64,
+    /// The balance after the change.
+    pub balance: u64,
+    pub kind: BalanceChangeKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_hash: Option<HashValue>,
+}
+
+/// The balance timeline of one mosaic of an account.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MosaicBalanceHistory {
+    pub mosaic_id: MosaicId,
+    /// The balance before the first entry.
+    pub opening_balance: u64,
+    /// The entries, in order of height.
+    pub entries: Vec<BalanceEntry>,
+}
+
+impl MosaicBalanceHistory {
+    /// The current balance, that is the balance after the last entry.
+    pub fn balance(&self) -> u64 {
+        self.entries
+            .last()
+            .map(|entry| entry.balance)
+            .unwrap_or(self.opening_balance)
+    }
+
+    /// The balance once the block at [height] was applied.
+    pub fn balance_at(&self, height: Height) -> u64 {
+        self.entries
+            .iter()
+            .take_while(|entry| *entry.height <= *height)
+            .last()
+            .map(|entry| entry.balance)
+            .unwrap_or(self.opening_balance)
+    }
+
+    fn recompute(&mut self) {
+        let mut balance = i128::from(self.opening_balance);
+        for entry in self.entries.iter_mut() {
+            balance = (balance + i128::from(entry.delta)).max(0);
+            entry.balance = u64::try_from(balance).unwrap_or(u64::MAX);
+        }
+    }
+}
+
+/// The balance timeline of every mosaic an account held.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceHistory {
+    pub address: Address,
+    /// The timeline of every mosaic, in order of mosaic id.
+    pub mosaics: Vec<MosaicBalanceHistory>,
+}
+
+impl BalanceHistory {
+    /// Builds the timelines of [address] from its balance [changes], starting from an
+    /// empty balance. [timestamps] gives the timestamp of the block at each height.
+    pub fn new(
+        address: Address,
+        mut changes: Vec<BalanceChange>,
+        timestamps: &HashMap<u64, Timestamp>,
+    ) -> Self {
+        changes.sort_by_key(|change| *change.height);
+
+        let mut mosaics: BTreeMap<u64, MosaicBalanceHistory> = BTreeMap::new();
+        for change in changes.into_iter() {
+            mosaics
+                .entry(change.mosaic_id.to_u64())
+                .or_insert_with(|| MosaicBalanceHistory {
+                    mosaic_id: change.mosaic_id,
+                    opening_balance: 0,
+                    entries: vec![],
+                })
+                .entries
+                .push(BalanceEntry {
+                    height: change.height,
+                    timestamp: timestamps.get(&*change.height).copied(),
+                    delta: change.delta,
+                    balance: 0,
+                    kind: change.kind,
+                    transaction_hash: change.transaction_hash,
+                });
+        }
+
+        let mut mosaics: Vec<MosaicBalanceHistory> = mosaics.into_values().collect();
+        for mosaic in mosaics.iter_mut() {
+            mosaic.recompute();
+        }
+
+        BalanceHistory { address, mosaics }
+    }
+
+    /// Sets the opening balance of every timeline so it ends at the current balance of
+    /// [account_info], accounting for changes the history does not cover.
+    pub fn reconcile(&mut self, account_info: &AccountInfo) {
+        for mosaic in account_info.mosaics.iter() {
+            let mosaic_id = match mosaic_id_of(mosaic) {
+                Some(mosaic_id) => mosaic_id,
+                None => continue,
+            };
+
+            if !self.mosaics.iter().any(|m| m.mosaic_id == mosaic_id) {
+                self.mosaics.push(MosaicBalanceHistory {
+                    mosaic_id,
+                    opening_balance: 0,
+                    entries: vec![],
+                });
+            }
+        }
+        self.mosaics.sort_by_key(|mosaic| mosaic.mosaic_id.to_u64());
+
+        for history in self.mosaics.iter_mut() {
+            let current = account_info
+                .mosaics
+                .iter()
+                .find(|mosaic| mosaic_id_of(mosaic) == Some(history.mosaic_id))
+                .map(|mosaic| i128::from(*mosaic.amount))
+                .unwrap_or_default();
+
+            let total: i128 = history
+                .entries
+                .iter()
+                .map(|entry| i128::from(entry.delta))
+                .sum();
+
+            history.opening_balance = u64::try_from((current - total).max(0)).unwrap_or(u64::MAX);
+            history.recompute();
+        }
+    }
+
+    pub fn mosaic(&self, mosaic_id: MosaicId) -> Option<&MosaicBalanceHistory> {
+        self.mosaics
+            .iter()
+            .find(|mosaic| mosaic.mosaic_id == mosaic_id)
+    }
+
+    /// The balance of [mosaic_id] once the block at [height] was applied.
+    pub fn balance_at(&self, mosaic_id: MosaicId, height: Height) -> u64 {
+        self.mosaic(mosaic_id)
+            .map(|mosaic| mosaic.balance_at(height))
+            .unwrap_or_default()
+    }
+
+    /// The non zero balances of every mosaic once the block at [height] was applied.
+    pub fn balances_at(&self, height: Height) -> Vec<(MosaicId, u64)> {
+        self.mosaics
+            .iter()
+            .map(|mosaic| (mosaic.mosaic_id, mosaic.balance_at(height)))
+            .filter(|(_, balance)| *balance > 0)
+            .collect()
+    }
+}
+
+impl core::fmt::Display for BalanceHistory {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+/// The balance of one account in a [PortfolioHolding].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioBalance {
+    pub address: Address,
+    pub amount: u64,
+}
+
+/// A mosaic held by the accounts of a [Portfolio].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioHolding {
+    pub mosaic_id: MosaicId,
+    /// The namespace names linked to the mosaic.
+    pub names: Vec<String>,
+    /// The amount held by all the accounts together.
+    pub amount: u64,
+    pub balances: Vec<PortfolioBalance>,
+}
+
+/// The mosaics held by a set of accounts.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Portfolio {
+    pub accounts: Vec<Address>,
+    /// The holdings, in order of mosaic id.
+    pub holdings: Vec<PortfolioHolding>,
+}
+
+impl Portfolio {
+    /// Builds the portfolio of [accounts], naming the mosaics with [names].
+    pub fn new(accounts: &[AccountInfo], names: &[MosaicNames]) -> Self {
+        let mut holdings: BTreeMap<u64, PortfolioHolding> = BTreeMap::new();
+
+        for account in accounts.iter() {
+            for mosaic in account.mosaics.iter() {
+                let mosaic_id = match mosaic_id_of(mosaic) {
+                    Some(mosaic_id) => mosaic_id,
+                    None => continue,
+                };
+
+                let holding =
+                    holdings
+                        .entry(mosaic_id.to_u64())
+                        .or_insert_with(|| PortfolioHolding {
+                            mosaic_id,
+                            names: names
+                                .iter()
+                                .find(|names| names.mosaic_id == mosaic_id)
+                                .map(|names| names.names.clone())
+                                .unwrap_or_default(),
+                            amount: 0,
+                            balances: vec![],
+                        });
+
+                holding.amount = holding.amount.saturating_add(*mosaic.amount);
+                holding.balances.push(PortfolioBalance {
+                    address: account.address,
+                    amount: *mosaic.amount,
+                });
+            }
+        }
+
+        Portfolio {
+            accounts: accounts.iter().map(|account| account.address).collect(),
+            holdings: holdings.into_values().collect(),
+        }
+    }
+
+    /// The mosaic ids held by the accounts.
+    pub fn mosaic_ids(&self) -> Vec<MosaicId> {
+        self.holdings
+            .iter()
+            .map(|holding| holding.mosaic_id)
+            .collect()
+    }
+
+    pub fn holding(&self, mosaic_id: MosaicId) -> Option<&PortfolioHolding> {
+        self.holdings
+            .iter()
+            .find(|holding| holding.mosaic_id == mosaic_id)
+    }
+}
+
+impl core::fmt::Display for Portfolio {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
@@ -0,0 +1,505 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use ::std::{collections::HashMap, io::Write};
+
+use chrono::Utc;
+
+use crate::{
+    models::{
+        exchange::OfferType,
+        message::MessageType,
+        mosaic::{MosaicAmount, MosaicId},
+        transaction::{
+            AddExchangeOfferTransaction, AggregateTransaction, Amount, ExchangeOfferTransaction,
+            FeeMultiplier, HashValue, Height, LockFundsTransaction, RemoveExchangeOfferTransaction,
+            Timestamp, Transaction, TransactionType, TransferTransaction,
+        },
+        AssetId, AssetIdType,
+    },
+    Result,
+};
+
+use super::{mosaic_id_of, transaction_fee, Address};
+
+/// The columns of an exported transaction history, in order.
+pub const HISTORY_COLUMNS: [&str; 10] = [
+    "timestamp",
+    "height",
+    "hash",
+    "type",
+    "direction",
+    "counterparty",
+    "mosaic",
+    "amount",
+    "fee",
+    "message",
+];
+
+/// How mosaics moved by a [HistoryEntry] relate to the exported account.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum HistoryDirection {
+    Incoming,
+    Outgoing,
+    /// Sent by the account to itself.
+    Internal,
+    /// Nothing was moved, or the transaction moves no mosaics.
+    None,
+}
+
+impl core::fmt::Display for HistoryDirection {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// One line of the transaction history of an account, before formatting.
+///
+/// A transaction is flattened into one entry per mosaic it moved for the account; an
+/// aggregate is flattened into the entries of its inner transactions.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryEntry {
+    pub height: Height,
+    /// The hash of the transaction, or of the aggregate containing it.
+    pub transaction_hash: HashValue,
+    pub transaction_type: TransactionType,
+    pub direction: HistoryDirection,
+    /// The address of the other account taking part, when there is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub counterparty: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mosaic_id: Option<MosaicId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Amount>,
+    /// The fee paid by the account, only set on the first entry of a transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee: Option<Amount>,
+    /// The message of a transfer, when it is a plain message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl HistoryEntry {
+    fn new(transaction: &dyn Transaction, height: Height, hash: HashValue) -> Self {
+        HistoryEntry {
+            height,
+            transaction_hash: hash,
+            transaction_type: transaction.entity_type(),
+            direction: HistoryDirection::None,
+            counterparty: None,
+            mosaic_id: None,
+            amount: None,
+            fee: None,
+            message: None,
+        }
+    }
+
+    fn with_mosaic(
+        mut self,
+        direction: HistoryDirection,
+        mosaic_id: Option<MosaicId>,
+        amount: Amount,
+    ) -> Self {
+        self.direction = direction;
+        self.mosaic_id = mosaic_id;
+        self.amount = Some(amount);
+        self
+    }
+
+    fn with_counterparty(mut self, counterparty: Address) -> Self {
+        self.counterparty = Some(counterparty);
+        self
+    }
+}
+
+impl core::fmt::Display for HistoryEntry {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+/// Flattens a confirmed [transaction] into the history entries of [address].
+///
+/// Transfers, exchange offers and lock funds are flattened into one entry per mosaic,
+/// inner transactions of an aggregate not involving [address] are skipped, and any
+/// other transaction signed by [address] gives one entry without mosaic. Namespace
+/// aliases should be resolved beforehand.
+pub fn history_entries(
+    address: &Address,
+    transaction: &dyn Transaction,
+    fee_multiplier: FeeMultiplier,
+    currency: MosaicId,
+) -> Vec<HistoryEntry> {
+    let height = transaction.height();
+    let hash = transaction.transaction_hash();
+    let is_signer = transaction.abs_transaction().signer.address == *address;
+
+    let mut entries = vec![];
+    if let Some(aggregate) = transaction.try_downcast_ref::<AggregateTransaction>() {
+        for inner in aggregate.inner_transactions.iter() {
+            entries.extend(inner_entries(
+                address,
+                inner.as_ref(),
+                height,
+                hash,
+                currency,
+            ));
+        }
+    } else {
+        entries.extend(inner_entries(address, transaction, height, hash, currency));
+    }
+
+    if is_signer {
+        if entries.is_empty() {
+            entries.push(HistoryEntry::new(transaction, height, hash));
+        }
+        entries[0].fee = Some(transaction_fee(transaction, fee_multiplier));
+    }
+
+    entries
+}
+
+fn asset_mosaic_id(asset_id: &dyn AssetId) -> Option<MosaicId> {
+    match asset_id.get_type() {
+        AssetIdType::MosaicIdType => Some(MosaicId::from(asset_id.to_u64())),
+        _ => None,
+    }
+}
+
+fn inner_entries(
+    address: &Address,
+    transaction: &dyn Transaction,
+    height: Height,
+    hash: HashValue,
+    currency: MosaicId,
+) -> Vec<HistoryEntry> {
+    let signer = transaction.abs_transaction().signer.address;
+    let is_signer = signer == *address;
+    let entry = || HistoryEntry::new(transaction, height, hash);
+
+    let mut entries = vec![];
+
+    if let Some(transfer) = transaction.try_downcast_ref::<TransferTransaction>() {
+        let is_recipient = transfer.recipient == *address;
+
+        let (direction, counterparty) = match (is_signer, is_recipient) {
+            (true, true) => (HistoryDirection::Internal, signer),
+            (true, false) => (HistoryDirection::Outgoing, transfer.recipient),
+            (false, true) => (HistoryDirection::Incoming, signer),
+            (false, false) => return entries,
+        };
+
+        let mut transfer_entry = entry().with_counterparty(counterparty);
+        transfer_entry.direction = direction;
+        if *transfer.message.message_type() == MessageType::PlainMessageType {
+            let message = String::from_utf8_lossy(transfer.message.payload_to_bytes());
+            if !message.is_empty() {
+                transfer_entry.message = Some(message.into_owned());
+            }
+        }
+
+        if transfer.mosaics.is_empty() {
+            entries.push(transfer_entry.clone());
+        }
+        for mosaic in transfer.mosaics.iter() {
+            entries.push(transfer_entry.clone().with_mosaic(
+                direction,
+                mosaic_id_of(mosaic),
+                mosaic.amount,
+            ));
+        }
+    } else if let Some(add) = transaction.try_downcast_ref::<AddExchangeOfferTransaction>() {
+        if is_signer {
+            for add_offer in add.offers.iter() {
+                let offer = &add_offer.offer;
+                entries.push(match offer.r#type {
+                    OfferType::BuyOffer => {
+                        entry().with_mosaic(HistoryDirection::Outgoing, Some(currency), offer.cost)
+                    }
+                    _ => entry().with_mosaic(
+                        HistoryDirection::Outgoing,
+                        mosaic_id_of(&offer.mosaic),
+                        offer.mosaic.amount,
+                    ),
+                });
+            }
+        }
+    } else if let Some(exchange) = transaction.try_downcast_ref::<ExchangeOfferTransaction>() {
+        for confirmation in exchange.confirmations.iter() {
+            let owner = confirmation.owner.address;
+            let is_owner = owner == *address;
+            if !is_signer && !is_owner {
+                continue;
+            }
+
+            let offer = &confirmation.offer;
+            let counterparty = if is_signer { owner } else { signer };
+
+            // The type is the one of the offer of the owner, whose side was locked when
+            // the offer was added.
+            let (mosaic_direction, cost_direction) = match (offer.r#type, is_signer) {
+                (OfferType::SellOffer, true) => (
+                    Some(HistoryDirection::Incoming),
+                    Some(HistoryDirection::Outgoing),
+                ),
+                (OfferType::SellOffer, false) => (None, Some(HistoryDirection::Incoming)),
+                (OfferType::BuyOffer, true) => (
+                    Some(HistoryDirection::Outgoing),
+                    Some(HistoryDirection::Incoming),
+                ),
+                (OfferType::BuyOffer, false) => (Some(HistoryDirection::Incoming), None),
+                _ => (None, None),
+            };
+
+            if let Some(direction) = mosaic_direction {
+                entries.push(entry().with_counterparty(counterparty).with_mosaic(
+                    direction,
+                    mosaic_id_of(&offer.mosaic),
+                    offer.mosaic.amount,
+                ));
+            }
+            if let Some(direction) = cost_direction {
+                entries.push(entry().with_counterparty(counterparty).with_mosaic(
+                    direction,
+                    Some(currency),
+                    offer.cost,
+                ));
+            }
+        }
+    } else if let Some(remove) = transaction.try_downcast_ref::<RemoveExchangeOfferTransaction>() {
+        if is_signer {
+            for offer in remove.offers.iter() {
+                let mut remove_entry = entry();
+                remove_entry.mosaic_id = asset_mosaic_id(offer.asset_id.as_ref());
+                entries.push(remove_entry);
+            }
+        }
+    } else if let Some(lock) = transaction.try_downcast_ref::<LockFundsTransaction>() {
+        if is_signer {
+            entries.push(entry().with_mosaic(
+                HistoryDirection::Outgoing,
+                mosaic_id_of(&lock.mosaic),
+                lock.mosaic.amount,
+            ));
+        }
+    } else if is_signer {
+        entries.push(entry());
+    }
+
+    entries
+}
+
+/// What is needed to display amounts of a mosaic.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MosaicFormat {
+    /// The first namespace name linked to the mosaic, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub divisibility: u8,
+}
+
+impl MosaicFormat {
+    fn format_amount(&self, amount: Amount) -> String {
+        match MosaicAmount::new(*amount, self.divisibility) {
+            Ok(amount) => amount.to_string(),
+            Err(_) => amount.to_string(),
+        }
+    }
+}
+
+/// One row of an exported transaction history, with every value formatted.
+///
+/// Amounts are in relative units of their mosaic, the fee is in the network currency
+/// and the timestamp is in RFC 3339 format, in UTC.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryRow {
+    pub timestamp: String,
+    pub height: u64,
+    pub hash: String,
+    #[serde(rename = "type")]
+    pub transaction_type: String,
+    pub direction: String,
+    pub counterparty: String,
+    /// The name of the mosaic, or its id in hex when it has no name.
+    pub mosaic: String,
+    pub amount: String,
+    pub fee: String,
+    pub message: String,
+}
+
+impl HistoryRow {
+    /// Formats [entry], using [formats] for the mosaics and [currency] for the fee.
+    ///
+    /// A mosaic missing from [formats] is shown by id with a divisibility of 0.
+    pub fn new(
+        entry: &HistoryEntry,
+        timestamp: Option<Timestamp>,
+        formats: &HashMap<MosaicId, MosaicFormat>,
+        currency: MosaicId,
+    ) -> Self {
+        let format_of = |mosaic_id: &MosaicId| {
+            formats.get(mosaic_id).cloned().unwrap_or(MosaicFormat {
+                name: None,
+                divisibility: 0,
+            })
+        };
+
+        let (mosaic, amount) = match entry.mosaic_id {
+            Some(ref mosaic_id) => {
+                let format = format_of(mosaic_id);
+                (
+                    format.name.clone().unwrap_or_else(|| mosaic_id.to_hex()),
+                    entry
+                        .amount
+                        .map(|amount| format.format_amount(amount))
+                        .unwrap_or_default(),
+                )
+            }
+            None => (String::new(), String::new()),
+        };
+
+        HistoryRow {
+            timestamp: timestamp
+                .map(|timestamp| timestamp.to_date_time().with_timezone(&Utc).to_rfc3339())
+                .unwrap_or_default(),
+            height: *entry.height,
+            hash: entry.transaction_hash.to_string(),
+            transaction_type: entry.transaction_type.to_string(),
+            direction: entry.direction.to_string(),
+            counterparty: entry
+                .counterparty
+                .map(|address| address.address_string())
+                .unwrap_or_default(),
+            mosaic,
+            amount,
+            fee: entry
+                .fee
+                .map(|fee| format_of(&currency).format_amount(fee))
+                .unwrap_or_default(),
+            message: entry.message.clone().unwrap_or_default(),
+        }
+    }
+
+    fn columns(&self) -> [String; 10] {
+        [
+            self.timestamp.clone(),
+            self.height.to_string(),
+            self.hash.clone(),
+            self.transaction_type.clone(),
+            self.direction.clone(),
+            self.counterparty.clone(),
+            self.mosaic.clone(),
+            self.amount.clone(),
+            self.fee.clone(),
+            self.message.clone(),
+        ]
+    }
+}
+
+impl core::fmt::Display for HistoryRow {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+/// The format of an exported transaction history.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ExportFormat {
+    /// Comma separated values with a header line.
+    Csv,
+    /// One JSON object per line.
+    JsonLines,
+}
+
+/// The characters a spreadsheet reads as the start of a formula.
+const CSV_FORMULA_PREFIXES: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+/// Escapes [value] for a CSV cell. Values that would be read as a formula, like a message
+/// starting with `=`, are prefixed with `'` so spreadsheets show them as text.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(&CSV_FORMULA_PREFIXES[..]) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+
+    if value.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+/// Writes [HistoryRow]s one at a time, so a history of any size can be exported.
+pub struct HistoryWriter<W: Write> {
+    writer: W,
+    format: ExportFormat,
+    rows: u64,
+    header_written: bool,
+}
+
+impl<W: Write> HistoryWriter<W> {
+    pub fn new(writer: W, format: ExportFormat) -> Self {
+        HistoryWriter {
+            writer,
+            format,
+            rows: 0,
+            header_written: false,
+        }
+    }
+
+    pub fn format(&self) -> ExportFormat {
+        self.format
+    }
+
+    /// The number of rows written so far.
+    pub fn rows(&self) -> u64 {
+        self.rows
+    }
+
+    fn write_header(&mut self) -> Result<()> {
+        if self.format == ExportFormat::Csv && !self.header_written {
+            writeln!(self.writer, "{}", HISTORY_COLUMNS.join(","))?;
+        }
+        self.header_written = true;
+        Ok(())
+    }
+
+    pub fn write_row(&mut self, row: &HistoryRow) -> Result<()> {
+        self.write_header()?;
+
+        match self.format {
+            ExportFormat::Csv => {
+                let line: Vec<String> = row.columns().iter().map(|c| csv_field(c)).collect();
+                writeln!(self.writer, "{}", line.join(","))?;
+            }
+            ExportFormat::JsonLines => {
+                writeln!(self.writer, "{}", serde_json::to_string(row)?)?;
+            }
+        }
+
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Writes the header if no row was written, flushes and returns the inner writer.
+    pub fn finish(mut self) -> Result<W> {
+        self.write_header()?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
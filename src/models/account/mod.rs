@@ -7,15 +7,23 @@
 pub use self::account_info::*;
 pub use self::account_model::*;
 pub use self::account_properties::*;
+pub use self::account_properties_diff::*;
 pub use self::account_type::*;
 pub use self::address_model::*;
+pub use self::balance_history::*;
+pub use self::history_export::*;
 pub use self::internally::*;
 pub use self::public_account_model::*;
+pub use self::signed_message::*;
 
 mod account_info;
 mod account_model;
 mod account_properties;
+mod account_properties_diff;
 mod account_type;
 mod address_model;
+mod balance_history;
+mod history_export;
 mod internally;
 mod public_account_model;
+mod signed_message;
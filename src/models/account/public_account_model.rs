@@ -17,7 +17,7 @@ use crate::{
     Result,
 };
 
-use super::Address;
+use super::{Address, SignedMessage};
 
 /// The [`PublicAccount`] account structure contains account's [`Address`] and public key.
 #[derive(Default, Clone, Deserialize, Copy)]
@@ -56,8 +56,17 @@ impl PublicAccount {
     ///
     /// Returns `Ok(())` if the signature is valid, and `Err` otherwise.
     pub fn verify_sign(&self, data: &str, signature: &str) -> Result<()> {
+        self.verify_data(data.as_bytes(), signature)
+    }
+
+    /// Verify a signature on raw data with this [`PublicAccount`] public key.
+    ///
+    /// # Return
+    ///
+    /// Returns `Ok(())` if the signature is valid, and `Err` otherwise.
+    pub fn verify_data(&self, data: &[u8], signature: &str) -> Result<()> {
         ensure!(
-            super::HASH512_LENGTH == (signature.len() / 2),
+            signature.len() == super::HASH512_LENGTH * 2,
             errors_const::ERR_INVALID_SIGNATURE_LENGTH
         );
 
@@ -71,7 +80,7 @@ impl PublicAccount {
 
         let signature = crypto::Signature::from_bytes(&sig_byte)?;
 
-        let verify = pk.verify(&data.as_bytes(), &signature);
+        let verify = pk.verify(data, &signature);
 
         if verify.is_ok() {
             Ok(())
@@ -80,6 +89,19 @@ impl PublicAccount {
         }
     }
 
+    /// Verify an off-chain [`SignedMessage`] and check that it was signed by this
+    /// [`PublicAccount`].
+    pub fn verify_message(&self, message: &SignedMessage) -> Result<()> {
+        ensure!(
+            message
+                .signer
+                .eq_ignore_ascii_case(&self.public_key_string()),
+            errors_const::ERR_SIGNED_MESSAGE_SIGNER_MISMATCH
+        );
+
+        message.verify()
+    }
+
     /// Convert this public key hex to a byte array.
     #[inline]
     fn decode(public_key: &str) -> [u8; PUBLIC_KEY_BYTES_SIZE] {
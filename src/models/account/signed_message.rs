@@ -0,0 +1,215 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use std::fmt;
+
+use crate::{
+    helpers::{hex_encode, is_hex},
+    models::{errors_const, network::NetworkType, transaction::HashValue},
+    Result,
+};
+
+use super::{Account, PublicAccount, HASH512_LENGTH};
+
+/// The fixed prefix of every off-chain signed message.
+///
+/// The leading `0x19` byte can never start a serialized transaction, so a signature over a
+/// signed message can not be replayed as a transaction signature.
+pub const SIGNED_MESSAGE_PREFIX: &[u8] = b"\x19Sirius Signed Message:\n";
+
+/// The current version of the signed message format.
+pub const SIGNED_MESSAGE_VERSION: u8 = 1;
+
+const FLAG_NETWORK_TYPE: u8 = 0x01;
+const FLAG_GENERATION_HASH: u8 = 0x02;
+
+/// Builds the domain-separated bytes that are signed for an off-chain message.
+///
+/// The layout is:
+/// prefix | version (1 byte) | flags (1 byte) | network type (1 byte, optional) |
+/// generation hash (32 bytes, optional) | data length (4 bytes LE) | data
+pub fn signed_message_bytes(
+    data: &[u8],
+    network_type: Option<NetworkType>,
+    generation_hash: Option<HashValue>,
+) -> Result<Vec<u8>> {
+    ensure!(
+        data.len() <= u32::MAX as usize,
+        errors_const::ERR_INVALID_DATA_LENGTH
+    );
+
+    let mut flags = 0u8;
+    if network_type.is_some() {
+        flags |= FLAG_NETWORK_TYPE;
+    }
+    if generation_hash.is_some() {
+        flags |= FLAG_GENERATION_HASH;
+    }
+
+    let mut bytes = Vec::with_capacity(SIGNED_MESSAGE_PREFIX.len() + 39 + data.len());
+    bytes.extend_from_slice(SIGNED_MESSAGE_PREFIX);
+    bytes.push(SIGNED_MESSAGE_VERSION);
+    bytes.push(flags);
+
+    if let Some(network_type) = network_type {
+        bytes.push(*network_type);
+    }
+
+    if let Some(generation_hash) = generation_hash {
+        bytes.extend_from_slice(generation_hash.as_bytes());
+    }
+
+    bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(data);
+
+    Ok(bytes)
+}
+
+/// A self-describing off-chain message signed by an [`Account`].
+///
+/// The structure carries everything a verifier needs: the signer public key, the signed data,
+/// the optional network and generation hash binding and the signature.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedMessage {
+    /// The version of the signed message format.
+    pub version: u8,
+    /// The public key of the signer formatted as hexadecimal.
+    pub signer: String,
+    /// The signed data formatted as hexadecimal.
+    pub payload: String,
+    /// The network the message is bound to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_type: Option<NetworkType>,
+    /// The generation hash of the chain the message is bound to, formatted as hexadecimal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generation_hash: Option<String>,
+    /// The signature formatted as hexadecimal.
+    pub signature: String,
+}
+
+impl SignedMessage {
+    /// Signs `data` with the given [`Account`], optionally binding the signature to a
+    /// [`NetworkType`] and a network generation hash.
+    pub fn create(
+        account: &Account,
+        data: &[u8],
+        network_type: Option<NetworkType>,
+        generation_hash: Option<HashValue>,
+    ) -> Result<Self> {
+        if let Some(hash) = generation_hash {
+            ensure!(
+                hash != HashValue::zero(),
+                errors_const::ERR_EMPTY_GENERATION_HASH
+            );
+        }
+
+        let signing = signed_message_bytes(data, network_type, generation_hash)?;
+
+        Ok(Self {
+            version: SIGNED_MESSAGE_VERSION,
+            signer: account.public_key_string(),
+            payload: hex_encode(data),
+            network_type,
+            generation_hash: generation_hash.map(|hash| hash.to_hex()),
+            signature: account.sign_data(&signing),
+        })
+    }
+
+    /// The [`PublicAccount`] that signed this message.
+    pub fn signer_public_account(&self) -> Result<PublicAccount> {
+        let network_type = self.network_type.unwrap_or_default();
+        PublicAccount::from_public_key(&self.signer, network_type)
+    }
+
+    /// The signed data, or an error if the payload is not valid hexadecimal.
+    pub fn payload_to_bytes(&self) -> Result<Vec<u8>> {
+        hex::decode(&self.payload)
+            .map_err(|_| failure::err_msg(errors_const::ERR_INVALID_PAYLOAD_HEX))
+    }
+
+    /// The signed data as UTF-8 text, if it is valid hexadecimal and valid UTF-8.
+    pub fn payload_to_string(&self) -> Option<String> {
+        String::from_utf8(self.payload_to_bytes().ok()?).ok()
+    }
+
+    /// Verifies the signature of this message against its own signer.
+    ///
+    /// # Return
+    ///
+    /// Returns `Ok(())` if the signature is valid, and `Err` otherwise.
+    pub fn verify(&self) -> Result<()> {
+        ensure!(
+            self.version == SIGNED_MESSAGE_VERSION,
+            errors_const::ERR_UNKNOWN_SIGNED_MESSAGE_VERSION
+        );
+
+        let payload = self.payload_to_bytes()?;
+
+        ensure!(
+            self.signature.len() == HASH512_LENGTH * 2,
+            errors_const::ERR_INVALID_SIGNATURE_LENGTH
+        );
+
+        ensure!(
+            is_hex(&self.signature),
+            errors_const::ERR_INVALID_SIGNATURE_HEX
+        );
+
+        let generation_hash = match &self.generation_hash {
+            Some(hash) => Some(hash.parse::<HashValue>()?),
+            None => None,
+        };
+
+        let signing = signed_message_bytes(&payload, self.network_type, generation_hash)?;
+
+        self.signer_public_account()?
+            .verify_data(&signing, &self.signature)
+    }
+
+    /// Verifies the signature of this message and checks that it was signed by `signer` for
+    /// the given network and generation hash.
+    ///
+    /// A message that is not bound to a network or generation hash is rejected, so a message
+    /// signed for one chain can not be replayed against another.
+    pub fn verify_for(
+        &self,
+        signer: &PublicAccount,
+        network_type: NetworkType,
+        generation_hash: HashValue,
+    ) -> Result<()> {
+        ensure!(
+            self.signer
+                .eq_ignore_ascii_case(&signer.public_key_string()),
+            errors_const::ERR_SIGNED_MESSAGE_SIGNER_MISMATCH
+        );
+
+        ensure!(
+            self.network_type == Some(network_type),
+            errors_const::ERR_SIGNED_MESSAGE_NETWORK_MISMATCH
+        );
+
+        ensure!(
+            self.generation_hash
+                .as_ref()
+                .map(|hash| hash.to_uppercase())
+                == Some(generation_hash.to_hex()),
+            errors_const::ERR_SIGNED_MESSAGE_GENERATION_HASH_MISMATCH
+        );
+
+        self.verify()
+    }
+}
+
+impl fmt::Display for SignedMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
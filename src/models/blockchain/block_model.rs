@@ -55,6 +55,9 @@ pub struct BlockInfo {
     /// The fee multiplier applied to transactions contained in block.
     pub fee_multiplier: i32,
 
+    /// The hash of this block.
+    pub hash: HashValue,
+
     /// The generation hash.
     pub generation_hash: HashValue,
 
@@ -0,0 +1,107 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use ::std::ops::RangeInclusive;
+
+use crate::{
+    models::{errors_const, transaction::Transactions},
+    Result,
+};
+
+use super::BlockInfo;
+
+/// The number of blocks requested per page when fetching a block range.
+pub const BLOCK_PAGE_SIZE: u64 = 100;
+
+/// The default number of block pages fetched at the same time.
+pub const BLOCK_RANGE_CONCURRENCY: usize = 4;
+
+/// Options of a block range request.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockRangeOptions {
+    /// The maximum number of block pages fetched at the same time.
+    pub concurrency: usize,
+    /// Whether the transactions of every block are fetched as well.
+    pub with_transactions: bool,
+}
+
+impl BlockRangeOptions {
+    pub fn new(concurrency: usize, with_transactions: bool) -> Result<Self> {
+        ensure!(concurrency > 0, errors_const::ERR_INVALID_CONCURRENCY);
+
+        Ok(BlockRangeOptions {
+            concurrency,
+            with_transactions,
+        })
+    }
+}
+
+impl Default for BlockRangeOptions {
+    fn default() -> Self {
+        BlockRangeOptions {
+            concurrency: BLOCK_RANGE_CONCURRENCY,
+            with_transactions: false,
+        }
+    }
+}
+
+impl core::fmt::Display for BlockRangeOptions {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+/// A block of a block range, with its transactions when they were requested.
+#[derive(Debug)]
+pub struct BlockWithTransactions {
+    pub block: BlockInfo,
+    /// The transactions of the block, `None` unless requested.
+    pub transactions: Option<Transactions>,
+}
+
+/// Returns the first height of every page of [page_size] blocks overlapping [range].
+///
+/// Pages are aligned the way the node aligns them, so the first page starts at the
+/// closest multiple of [page_size] plus one below the start of [range].
+pub(crate) fn block_pages(range: &RangeInclusive<u64>, page_size: u64) -> Result<Vec<u64>> {
+    ensure!(*range.start() > 0, errors_const::ERR_INVALID_BLOCK_HEIGHT);
+
+    ensure!(!range.is_empty(), errors_const::ERR_EMPTY_BLOCK_RANGE);
+
+    ensure!(page_size > 0, errors_const::ERR_INVALID_BLOCK_LIMIT);
+
+    let first = (range.start() - 1) / page_size * page_size + 1;
+
+    Ok((first..=*range.end()).step_by(page_size as usize).collect())
+}
+
+/// Keeps the blocks of [page] that are part of [range], ordered by height, checking
+/// none of them is missing.
+pub(crate) fn blocks_in_page(
+    mut page: Vec<BlockInfo>,
+    page_start: u64,
+    page_size: u64,
+    range: &RangeInclusive<u64>,
+) -> Result<Vec<BlockInfo>> {
+    let start = page_start.max(*range.start());
+    let end = (page_start + page_size - 1).min(*range.end());
+
+    page.retain(|block| *block.height >= start && *block.height <= end);
+    page.sort_by_key(|block| *block.height);
+    page.dedup_by_key(|block| *block.height);
+
+    ensure!(
+        page.len() as u64 == end - start + 1,
+        errors_const::ERR_MISSING_BLOCKS
+    );
+
+    Ok(page)
+}
@@ -0,0 +1,245 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use ::std::collections::{BTreeMap, HashMap};
+
+use crate::{
+    models::{
+        errors_const,
+        transaction::{HashValue, Height, Transaction},
+    },
+    Result,
+};
+
+use super::BlockInfo;
+
+/// What happened to a tracked transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ConfirmationStatus {
+    /// The block of the transaction is part of the chain, with this many blocks on top of
+    /// it counting its own block.
+    Confirmed(u64),
+    /// The transaction is deep enough in the chain not to be rolled back any more. It is
+    /// no longer tracked.
+    Finalized,
+    /// The block of the transaction was rolled back by a chain reorganization. It is no
+    /// longer tracked, the transaction may be confirmed again in another block.
+    RolledBack,
+}
+
+/// A change of the confirmation of a tracked transaction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfirmationEvent {
+    pub transaction_hash: HashValue,
+    /// The height of the block the transaction was confirmed in.
+    pub height: Height,
+    /// The hash of the block the transaction was confirmed in.
+    pub block_hash: HashValue,
+    pub status: ConfirmationStatus,
+}
+
+impl core::fmt::Display for ConfirmationEvent {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+struct TrackedTransaction {
+    height: u64,
+    block_hash: HashValue,
+    depth: u64,
+}
+
+/// Follows the confirmation depth of transactions as new blocks arrive, and tells when
+/// they are finalized or rolled back by a chain reorganization.
+///
+/// The tracker remembers the hash of the recent blocks it was given. A block arriving at
+/// a height that was already seen with another hash, or whose previous block hash does
+/// not match the one remembered, means the chain reorganized, and every tracked
+/// transaction at or above the fork is rolled back.
+#[derive(Debug, Clone)]
+pub struct ConfirmationTracker {
+    finality_depth: u64,
+    chain_height: u64,
+    blocks: BTreeMap<u64, HashValue>,
+    tracked: HashMap<HashValue, TrackedTransaction>,
+}
+
+impl ConfirmationTracker {
+    /// Creates a tracker finalizing transactions once [finality_depth] blocks, counting
+    /// their own, are part of the chain.
+    pub fn new(finality_depth: u64) -> Result<Self> {
+        ensure!(finality_depth > 0, errors_const::ERR_INVALID_FINALITY_DEPTH);
+
+        Ok(ConfirmationTracker {
+            finality_depth,
+            chain_height: 0,
+            blocks: BTreeMap::new(),
+            tracked: HashMap::new(),
+        })
+    }
+
+    pub fn finality_depth(&self) -> u64 {
+        self.finality_depth
+    }
+
+    /// The height of the last block applied, 0 before any.
+    pub fn chain_height(&self) -> u64 {
+        self.chain_height
+    }
+
+    pub fn len(&self) -> usize {
+        self.tracked.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tracked.is_empty()
+    }
+
+    pub fn is_tracked(&self, transaction_hash: &HashValue) -> bool {
+        self.tracked.contains_key(transaction_hash)
+    }
+
+    /// Tracks the transaction [transaction_hash], confirmed in the block of [height] whose
+    /// hash is [block_hash].
+    pub fn track(&mut self, transaction_hash: HashValue, height: Height, block_hash: HashValue) {
+        self.tracked.insert(
+            transaction_hash,
+            TrackedTransaction {
+                height: *height,
+                block_hash,
+                depth: 0,
+            },
+        );
+    }
+
+    /// Tracks a confirmed [transaction] included in [block].
+    ///
+    /// An inner transaction of an aggregate is tracked by the hash of the aggregate.
+    pub fn track_transaction(
+        &mut self,
+        transaction: &dyn Transaction,
+        block: &BlockInfo,
+    ) -> Result<()> {
+        let info = match transaction.abs_transaction().transaction_info {
+            Some(info) if info.height == block.height => info,
+            _ => bail!(errors_const::ERR_TRANSACTION_NOT_IN_BLOCK),
+        };
+
+        let transaction_hash = match info.hash.or(info.aggregate_hash) {
+            Some(hash) => hash,
+            None => bail!(errors_const::ERR_TRANSACTION_NOT_IN_BLOCK),
+        };
+
+        self.track(transaction_hash, block.height, block.hash);
+        Ok(())
+    }
+
+    /// Stops tracking [transaction_hash], returning `true` if it was tracked.
+    pub fn untrack(&mut self, transaction_hash: &HashValue) -> bool {
+        self.tracked.remove(transaction_hash).is_some()
+    }
+
+    /// Applies a new [block] of the chain and returns the events it caused, ordered by
+    /// height.
+    pub fn apply_block(&mut self, block: &BlockInfo) -> Vec<ConfirmationEvent> {
+        let height = *block.height;
+
+        let mut fork_height = None;
+
+        if height > 1 {
+            if let Some(known) = self.blocks.get(&(height - 1)) {
+                if *known != block.previous_block_hash {
+                    fork_height = Some(height - 1);
+                }
+            }
+        }
+
+        if fork_height.is_none() {
+            match self.blocks.get(&height) {
+                Some(known) if *known != block.hash => fork_height = Some(height),
+                // A block arriving below the chain height rolls back the blocks above it.
+                _ if height <= self.chain_height => fork_height = Some(height + 1),
+                _ => {}
+            }
+        }
+
+        if let Some(fork_height) = fork_height {
+            self.blocks.split_off(&fork_height);
+        }
+
+        if height > 1 {
+            self.blocks.insert(height - 1, block.previous_block_hash);
+        }
+        self.blocks.insert(height, block.hash);
+        self.chain_height = height;
+
+        let mut hashes: Vec<(u64, HashValue)> = self
+            .tracked
+            .iter()
+            .map(|(hash, tracked)| (tracked.height, *hash))
+            .collect();
+        hashes.sort();
+
+        let mut events = vec![];
+        for (_, transaction_hash) in hashes.into_iter() {
+            let tracked = match self.tracked.get_mut(&transaction_hash) {
+                Some(tracked) => tracked,
+                None => continue,
+            };
+
+            let rolled_back = match fork_height {
+                Some(fork_height) if tracked.height >= fork_height => true,
+                _ => match self.blocks.get(&tracked.height) {
+                    Some(known) => *known != tracked.block_hash,
+                    None => false,
+                },
+            };
+
+            let status = if rolled_back {
+                ConfirmationStatus::RolledBack
+            } else if tracked.height > height {
+                continue;
+            } else {
+                let depth = height - tracked.height + 1;
+                if depth >= self.finality_depth {
+                    ConfirmationStatus::Finalized
+                } else if depth > tracked.depth {
+                    tracked.depth = depth;
+                    ConfirmationStatus::Confirmed(depth)
+                } else {
+                    continue;
+                }
+            };
+
+            events.push(ConfirmationEvent {
+                transaction_hash,
+                height: Height::new(tracked.height),
+                block_hash: tracked.block_hash,
+                status,
+            });
+
+            match status {
+                ConfirmationStatus::Confirmed(_) => {}
+                _ => {
+                    self.tracked.remove(&transaction_hash);
+                }
+            }
+        }
+
+        // Only the blocks that can still be rolled back are remembered.
+        let oldest = height.saturating_sub(self.finality_depth);
+        self.blocks = self.blocks.split_off(&oldest);
+
+        events
+    }
+}
@@ -0,0 +1,167 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use std::{fmt, time::Duration};
+
+use crate::models::{
+    mosaic::{MosaicId, MosaicInfo},
+    namespace::{NamespaceId, NamespaceInfo},
+    transaction::Height,
+    Uint64,
+};
+
+use super::BlockInfo;
+
+/// The number of seconds in a day.
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Returns the average time between the given blocks, or `None` when there are
+/// less than two blocks.
+pub fn average_block_time(blocks: &[BlockInfo]) -> Option<Duration> {
+    let first = blocks.iter().min_by_key(|block| *block.height)?;
+    let last = blocks.iter().max_by_key(|block| *block.height)?;
+
+    let blocks_between = last.height.checked_sub(*first.height)?;
+    if blocks_between == 0 {
+        return None;
+    }
+
+    let first_ms = *first.timestamp.to_blockchain_timestamp().to_uint64();
+    let last_ms = *last.timestamp.to_blockchain_timestamp().to_uint64();
+
+    Some(Duration::from_millis(
+        last_ms.saturating_sub(first_ms) / blocks_between,
+    ))
+}
+
+/// Returns the number of blocks needed to cover [duration], rounded up.
+pub fn blocks_for_duration(duration: Duration, block_time: Duration) -> u64 {
+    let block_ms = block_time.as_millis().max(1);
+
+    let millis = duration.as_millis();
+    // One more block for any remainder, without `div_ceil` for older toolchains.
+    let blocks = millis / block_ms + (millis % block_ms).min(1);
+
+    blocks as u64
+}
+
+/// An asset with a limited lifetime.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExpiringAsset {
+    Namespace(NamespaceId),
+    Mosaic(MosaicId),
+}
+
+impl fmt::Display for ExpiringAsset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExpiringAsset::Namespace(id) => write!(f, "namespace {}", id),
+            ExpiringAsset::Mosaic(id) => write!(f, "mosaic {}", id),
+        }
+    }
+}
+
+/// When an asset expires, relative to a chain height.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Expiry {
+    pub asset: ExpiringAsset,
+    /// The height at which the asset expires.
+    pub end_height: Height,
+    /// The chain height the expiry was calculated at.
+    pub current_height: Height,
+    /// The average time between blocks used to estimate the time left.
+    pub block_time: Duration,
+}
+
+impl Expiry {
+    pub fn new(
+        asset: ExpiringAsset,
+        end_height: Height,
+        current_height: Height,
+        block_time: Duration,
+    ) -> Self {
+        Expiry {
+            asset,
+            end_height,
+            current_height,
+            block_time,
+        }
+    }
+
+    /// Returns true if the asset is expired at the current height.
+    pub fn is_expired(&self) -> bool {
+        *self.current_height >= *self.end_height
+    }
+
+    /// The number of blocks left until the asset expires, zero when it is expired.
+    pub fn blocks_left(&self) -> u64 {
+        self.end_height.saturating_sub(*self.current_height)
+    }
+
+    /// The estimated time left until the asset expires.
+    pub fn time_left(&self) -> Duration {
+        Duration::from_millis(
+            (self.block_time.as_millis() as u64).saturating_mul(self.blocks_left()),
+        )
+    }
+
+    /// The estimated number of whole days left until the asset expires.
+    pub fn days_left(&self) -> u64 {
+        self.time_left().as_secs() / SECONDS_PER_DAY
+    }
+
+    /// Returns true if the asset expires within [window] from the current height.
+    pub fn expires_within(&self, window: Duration) -> bool {
+        self.blocks_left() <= blocks_for_duration(window, self.block_time)
+    }
+
+    /// The rental duration in blocks needed so the asset does not expire
+    /// within [keep_for] from the current height, zero when it does not need renewal.
+    pub fn renewal_duration(&self, keep_for: Duration) -> Uint64 {
+        Uint64::new(
+            blocks_for_duration(keep_for, self.block_time).saturating_sub(self.blocks_left()),
+        )
+    }
+}
+
+impl fmt::Display for Expiry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+impl NamespaceInfo {
+    /// Returns when the namespace expires, relative to [current_height].
+    pub fn expiry(&self, current_height: Height, block_time: Duration) -> Expiry {
+        Expiry::new(
+            ExpiringAsset::Namespace(self.namespace_id),
+            self.end_height,
+            current_height,
+            block_time,
+        )
+    }
+}
+
+impl MosaicInfo {
+    /// Returns when the mosaic expires, relative to [current_height], or `None`
+    /// when the mosaic does not expire.
+    pub fn expiry(&self, current_height: Height, block_time: Duration) -> Option<Expiry> {
+        self.end_height().map(|end_height| {
+            Expiry::new(
+                ExpiringAsset::Mosaic(self.mosaic_id),
+                end_height,
+                current_height,
+                block_time,
+            )
+        })
+    }
+}
@@ -5,7 +5,13 @@
  */
 
 pub use self::block_model::*;
+pub use self::block_range::*;
 pub use self::blockchain_model::*;
+pub use self::confirmation_tracker::*;
+pub use self::expiry::*;
 
 mod block_model;
+mod block_range;
 mod blockchain_model;
+mod confirmation_tracker;
+mod expiry;
@@ -22,6 +22,7 @@ pub const ALIAS_TRANSACTION_HEADER: usize =
     TRANSACTION_HEADER_SIZE + NAMESPACE_SIZE + ALIAS_ACTION_SIZE;
 pub const AMOUNT_SIZE: usize = 8;
 pub const BASE_INT64SIZE: usize = 8;
+pub const COSIGNATURE_SIZE: usize = SIGNER_SIZE + SIGNATURE_SIZE;
 pub const DEAD_LINE_SIZE: usize = 8;
 pub const DURATION_SIZE: usize = 8;
 pub const HALF_OF_SIGNATURE: usize = SIGNATURE_SIZE / 2;
@@ -36,6 +37,9 @@ pub const MIN_REMOVAL_SIZE: usize = 1;
 pub const MODIFICATIONS_SIZE_SIZE: usize = 1;
 pub const MODIFY_MULTISIG_HEADER_SIZE: usize =
     TRANSACTION_HEADER_SIZE + MIN_APPROVAL_SIZE + MIN_REMOVAL_SIZE + MODIFICATIONS_SIZE_SIZE;
+pub const MULTISIG_MAX_COSIGNATORIES: usize = 10;
+pub const MULTISIG_MAX_COSIGNED_ACCOUNTS: usize = 5;
+pub const MULTISIG_MAX_DEPTH: usize = 3;
 pub const MOSAICS_SIZE_SIZE: usize = 1;
 pub const MOSAIC_DEFINITION_TRANSACTION_HEADER_SIZE: usize =
     TRANSACTION_HEADER_SIZE + MOSAIC_NONCE_SIZE + MOSAIC_ID_SIZE + MOSAIC_PROPERTIES_HEADER_SIZE;
@@ -83,6 +87,9 @@ pub const EXCHANGE_OFFER_HEADER_SIZE: usize = TRANSACTION_HEADER_SIZE + OFFERS_C
 pub const EXCHANGE_OFFER_SIZE: usize = DURATION_SIZE + 2 * AMOUNT_SIZE + OFFER_TYPE_SIZE + KEY_SIZE;
 pub const METADATA_HEADER_SIZE: usize = TRANSACTION_HEADER_SIZE + METADATA_TYPE_SIZE;
 pub const METADATA_TYPE_SIZE: usize = 1;
+pub const METADATA_MAX_FIELDS: usize = 10;
+pub const METADATA_MAX_KEY_SIZE: usize = 128;
+pub const METADATA_MAX_VALUE_SIZE: usize = 1024;
 //pub const HASH_TYPE_SIZE: usize = 1;
 //pub const LINK_ACTION_SIZE: usize = 1;
 //pub const MODIFY_CONTRACT_HEADER_SIZE: usize = TRANSACTION_HEADER_SIZE + DURATION_SIZE + HASH256 + 3 * MODIFICATIONS_SIZE_SIZE;
@@ -35,6 +35,16 @@ pub enum Error {
 
 impl ::failure::Fail for Error {}
 
+impl Error {
+    /// Returns true if the node answered that the requested resource does not exist.
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Error::SiriusError(e) => e.code == "ResourceNotFound",
+            _ => false,
+        }
+    }
+}
+
 impl From<reqwest::Error> for Error {
     fn from(reqwest: reqwest::Error) -> Self {
         Error::Reqwest(reqwest)
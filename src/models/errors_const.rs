@@ -4,6 +4,7 @@
 
 // Common errors
 pub const ERR_INVALID_NAMESPACE_ALIASED: &str = "Namespace is not aliased to Mosaic";
+pub const ERR_INVALID_NAMESPACE_ADDRESS_ALIASED: &str = "Namespace is not aliased to Address";
 pub const ERR_INVALID_ASSET_ID: &str = "AssetId's vector should not be empty";
 
 // Accounts errors
@@ -13,6 +14,11 @@ pub const ERR_INVALID_ACCOUNT_ID: &str = "Invalid account_id";
 pub const ERR_EMPTY_ADDRESSES: &str = "address string must not be empty.";
 pub const ERR_INVALID_ADDRESSES_LEN: &str = "Invalid len address.";
 pub const ERR_INVALID_ADDRESSES_HEX: &str = "Invalid Address it's not hex.";
+pub const ERR_UNKNOWN_SIGNED_MESSAGE_VERSION: &str = "Not supported signed message version.";
+pub const ERR_SIGNED_MESSAGE_SIGNER_MISMATCH: &str = "Signed message signer does not match.";
+pub const ERR_SIGNED_MESSAGE_NETWORK_MISMATCH: &str = "Signed message network type does not match.";
+pub const ERR_SIGNED_MESSAGE_GENERATION_HASH_MISMATCH: &str =
+    "Signed message generation hash does not match.";
 
 // Namespace errors
 pub const ERR_NAMESPACE_TOO_MANY_PART: &str = "Too many parts";
@@ -21,12 +27,45 @@ pub const ERR_WRONG_BIT_NAMESPACE_ID: &str = "Namespace_id doesn't have 64th bit
 pub const ERR_EMPTY_NAMESPACE_IDS: &str = "Namespace ids vec must not by empty";
 pub const ERR_EMPTY_NAMESPACE_NAME: &str = "Namespace name must not by empty";
 pub const ERR_INVALID_NAMESPACE_NAME: &str = "Namespace name is invalid";
+pub const ERR_NAMESPACE_PATH_REGISTERED: &str =
+    "Every level of the namespace is already registered";
+pub const ERR_NAMESPACE_OWNED_BY_OTHER: &str = "Namespace is owned by another account";
+pub const ERR_NAMESPACE_NOT_ROOT: &str = "Only root namespaces can be renewed.";
+pub const ERR_NAMESPACE_NAME_MISMATCH: &str = "The name does not match the namespace id.";
+pub const ERR_INVALID_RENEWAL_DURATION: &str = "The renewal duration must be greater than zero.";
 
 // Mosaic errors
 pub const ERR_EMPTY_MOSAIC_IDS: &str = "Mosaics ids vec must not by empty";
 pub const ERR_EMPTY_MOSAIC_ID: &str = "MosaicId must not be empty";
 pub const ERR_INVALID_MOSAIC_PROPERTIES: &str = "Mosaic Properties is not valid.";
 pub const ERR_INVALID_MOSAIC_PROPERTY_ID: &str = "Unknown Property Id.";
+pub const ERR_INVALID_MOSAIC_DIVISIBILITY: &str = "Mosaic divisibility must not exceed 19.";
+pub const ERR_INVALID_MOSAIC_AMOUNT: &str = "Invalid mosaic amount it's not a decimal number.";
+pub const ERR_MOSAIC_AMOUNT_PRECISION: &str =
+    "The mosaic amount has more decimal places than the mosaic divisibility.";
+pub const ERR_MOSAIC_AMOUNT_OVERFLOW: &str = "The mosaic amount is out of range.";
+pub const ERR_MOSAIC_DIVISIBILITY_MISMATCH: &str =
+    "The mosaic amounts have a different divisibility.";
+
+// Multisig errors
+pub const ERR_MULTISIG_ACCOUNT_NOT_IN_GRAPH: &str =
+    "The multisig account is not part of the multisig graph.";
+pub const ERR_MULTISIG_NO_CHANGE: &str = "The multisig modification does not change anything.";
+pub const ERR_MULTISIG_COSIGNATORY_PRESENT: &str = "The account is already a cosignatory.";
+pub const ERR_MULTISIG_COSIGNATORY_NOT_PRESENT: &str = "The account is not a cosignatory.";
+pub const ERR_MULTISIG_SELF_COSIGNATORY: &str = "An account can not be its own cosignatory.";
+pub const ERR_MULTISIG_MULTIPLE_REMOVALS: &str =
+    "Only one cosignatory can be removed per transaction.";
+pub const ERR_MULTISIG_TOO_MANY_COSIGNATORIES: &str = "Too many cosignatories.";
+pub const ERR_MULTISIG_TOO_MANY_COSIGNED_ACCOUNTS: &str =
+    "The cosignatory already cosigns too many accounts.";
+pub const ERR_MULTISIG_INVALID_MIN_APPROVAL: &str =
+    "The min approval must be between 1 and the number of cosignatories.";
+pub const ERR_MULTISIG_INVALID_MIN_REMOVAL: &str =
+    "The min removal must be between 1 and the number of cosignatories.";
+pub const ERR_MULTISIG_DELTA_OUT_OF_RANGE: &str = "The threshold change is out of range.";
+pub const ERR_MULTISIG_LOOP: &str = "The cosignatory would create a loop in the multisig graph.";
+pub const ERR_MULTISIG_MAX_DEPTH: &str = "The multisig graph would be too deep.";
 
 // Transaction errors
 pub const ERR_EMPTY_TRANSACTION_HASHES: &str = "Transaction hashes vec must not by empty";
@@ -36,6 +75,8 @@ pub const ERR_EMPTY_MODIFICATIONS: &str = "modifications must not empty";
 pub const ERR_EMPTY_TRANSACTION_SIGNER: &str = "some of the transaction does not have a signer";
 pub const ERR_EMPTY_COSIGNATURE_HASH: &str = "Cosignature transaction hash it should not be empty";
 pub const ERR_EMPTY_GENERATION_HASH: &str = "Generation hash it should not be empty";
+pub const ERR_TRANSACTION_NOT_CONFIRMED: &str = "The transaction is not confirmed.";
+pub const ERR_UNRESOLVED_ALIAS: &str = "The namespace alias has no resolution in the block.";
 pub const ERR_INVALID_AGGREGATE_TRANSACTION: &str =
     "The transaction is not an AggregateTransaction.";
 
@@ -44,6 +85,10 @@ pub const ERR_UNKNOWN_BLOCKCHAIN_TYPE: &str = "Not supported Blockchain Type. ";
 pub const ERR_INVALID_HASH_LENGTH: &str = "The length of Hash is invalid.";
 pub const ERR_INVALID_HASH_HEX: &str = "Invalid Hash it's not hex.";
 pub const ERR_INVALID_PAYLOAD_HEX: &str = "Invalid payload it's not hex.";
+pub const ERR_SECURE_MESSAGE_DECRYPTION: &str =
+    "Failed to decrypt the message, the key is wrong or the payload is corrupt.";
+pub const ERR_INVALID_HARVESTING_MESSAGE_LENGTH: &str =
+    "The length of the delegated harvesting message payload is invalid.";
 
 //pub const ERR_EMPTY_HASH: &str = "Hash must not by empty.";
 pub const ERR_EMPTY_NETWORK_TYPE: &str = "NetworkType string is empty.";
@@ -56,9 +101,64 @@ pub const ERR_INVALID_SIGNATURE_LENGTH: &str = "The length of Signature is inval
 pub const ERR_INVALID_SIGNATURE_HEX: &str = "Signature must be hexadecimal.";
 pub const ERR_UNKNOWN_TYPE: &str = "Not supported value Type.";
 pub const ERR_INVALID_DATA_LENGTH: &str = "The length of data is invalid.";
+pub const ERR_NOT_ENOUGH_BLOCKS: &str = "Not enough blocks to estimate the block time.";
+pub const ERR_INVALID_BLOCK_HEIGHT: &str = "Block height should not be zero.";
+pub const ERR_INVALID_BLOCK_LIMIT: &str = "Limit should be greater than zero.";
+pub const ERR_EMPTY_BLOCK_RANGE: &str = "The block range is empty.";
+pub const ERR_MISSING_BLOCKS: &str = "Some blocks of the range are not part of the chain.";
+pub const ERR_INVALID_CONCURRENCY: &str = "Concurrency should be greater than zero.";
+pub const ERR_INVALID_FINALITY_DEPTH: &str = "The finality depth should be greater than zero.";
+pub const ERR_TRANSACTION_NOT_IN_BLOCK: &str = "The transaction is not part of the block.";
+
+// Exchange errors
+pub const ERR_INSUFFICIENT_EXCHANGE_OFFERS: &str =
+    "The matching offers do not cover the requested amount.";
+pub const ERR_INVALID_EXCHANGE_OFFER_TYPE: &str = "The offer type must be a buy or sell offer.";
 
 // Metadata errors
 pub const ERR_METADATA_EMPTY_ADDRESSES: &str = "list adresses ids must not by empty.";
 pub const ERR_METADATA_EMPTY_MOSAIC_IDS: &str = "list mosaics ids must not by empty.";
 pub const ERR_METADATA_EMPTY_NAMESPACE_IDS: &str = "list namespaces ids must not by empty.";
 pub const ERR_METADATA_EMPTY_MODIFICATIONS: &str = "modifications must not empty.";
+pub const ERR_METADATA_EMPTY_KEY: &str = "metadata key must not be empty.";
+pub const ERR_METADATA_KEY_TOO_LONG: &str = "metadata key is too long.";
+pub const ERR_METADATA_VALUE_TOO_LONG: &str = "metadata value is too long.";
+pub const ERR_METADATA_TOO_MANY_FIELDS: &str = "too many metadata fields.";
+pub const ERR_METADATA_DUPLICATE_KEY: &str = "metadata key is modified more than once.";
+
+// Account properties errors
+pub const ERR_INVALID_ACCOUNT_PROPERTY_TYPE: &str =
+    "The property type does not match the kind of the modifications.";
+pub const ERR_ACCOUNT_PROPERTY_ALLOW_AND_BLOCK: &str =
+    "An account can not both allow and block the same kind of property.";
+pub const ERR_ACCOUNT_PROPERTY_ALREADY_PRESENT: &str = "The account property is already present.";
+pub const ERR_ACCOUNT_PROPERTY_NOT_PRESENT: &str = "The account property to remove is not present.";
+pub const ERR_ACCOUNT_PROPERTY_DUPLICATE: &str = "The account property is listed more than once.";
+pub const ERR_INVALID_ACCOUNT_PROPERTY_MODIFICATION: &str =
+    "Unknown account property modification type.";
+
+// Indexer errors
+pub const ERR_INDEXER_NOT_NEXT_BLOCK: &str = "The block does not follow the last indexed block.";
+pub const ERR_INDEXER_INVALID_BATCH_SIZE: &str = "The batch size should be greater than zero.";
+
+// Node discovery errors
+pub const ERR_EMPTY_NODE_URLS: &str = "The list of seed node urls must not be empty.";
+pub const ERR_NO_HEALTHY_NODES: &str = "No healthy api node was found.";
+
+// Clock skew errors
+pub const ERR_INVALID_NODE_TIME: &str = "The node time is missing its communication timestamps.";
+pub const ERR_INVALID_CLOCK_SAMPLES: &str = "The number of samples should be greater than zero.";
+pub const ERR_CLOCK_SKEW_TOO_LARGE: &str =
+    "The skew between the local clock and the node clock exceeds the limit.";
+
+// Deadline errors
+pub const ERR_DEADLINE_EXPIRED: &str = "The deadline has already expired.";
+pub const ERR_DEADLINE_TOO_FAR: &str = "The deadline exceeds the maximum deadline window.";
+pub const ERR_INVALID_DEADLINE_DURATION: &str = "The deadline duration is out of range.";
+
+// Network properties errors
+pub const ERR_INVALID_NETWORK_CONFIG: &str = "The network configuration is invalid.";
+pub const ERR_MESSAGE_TOO_LARGE: &str = "The message exceeds the maximum message size.";
+pub const ERR_TRANSACTION_NETWORK_MISMATCH: &str =
+    "The transaction network type does not match the network.";
+pub const ERR_DEADLINE_EPOCH_MISMATCH: &str = "The deadline is not on the network epoch.";
@@ -6,7 +6,10 @@
 
 use std::fmt;
 
-use {num_enum::IntoPrimitive, std::collections::HashMap};
+use {
+    num_enum::IntoPrimitive,
+    std::{cmp::Ordering, collections::HashMap},
+};
 
 use crate::models::{
     account::PublicAccount,
@@ -150,6 +153,53 @@ pub struct OfferInfo {
     pub deadline: Height,
 }
 
+impl OfferInfo {
+    /// Returns the price of one unit of the mosaic, as a floating point number.
+    ///
+    /// Use [OfferInfo::cmp_price] to compare prices exactly.
+    pub fn price(&self) -> f64 {
+        if *self.price_denominator == 0 {
+            return 0.0;
+        }
+
+        *self.price_numerator as f64 / *self.price_denominator as f64
+    }
+
+    /// Compares the price of this offer to the price of [other] without rounding.
+    pub fn cmp_price(&self, other: &OfferInfo) -> Ordering {
+        let lhs = u128::from(*self.price_numerator) * u128::from(*other.price_denominator);
+        let rhs = u128::from(*other.price_numerator) * u128::from(*self.price_denominator);
+
+        lhs.cmp(&rhs)
+    }
+
+    /// Returns the cost of [amount] units of the mosaic at the price of the offer.
+    ///
+    /// The cost of filling a sell offer is rounded up and the cost of filling a buy
+    /// offer is rounded down, so the owner of the offer never gets a worse price.
+    pub fn cost(&self, offer_type: OfferType, amount: Amount) -> Amount {
+        if *self.price_denominator == 0 {
+            return Uint64::default();
+        }
+
+        let total = u128::from(*self.price_numerator) * u128::from(*amount);
+        let denominator = u128::from(*self.price_denominator);
+
+        let cost = match offer_type {
+            // Rounded up without `div_ceil` for older toolchains.
+            OfferType::SellOffer => total / denominator + (total % denominator).min(1),
+            _ => total / denominator,
+        };
+
+        Uint64::new(cost.min(u128::from(u64::MAX)) as u64)
+    }
+
+    /// Returns true if the offer is expired at the given height.
+    pub fn is_expired_at(&self, height: Height) -> bool {
+        *height >= *self.deadline
+    }
+}
+
 impl core::fmt::Display for OfferInfo {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(
@@ -5,5 +5,9 @@
  */
 
 pub use self::exchange_model::*;
+pub use self::offer_tracker::*;
+pub use self::order_book::*;
 
 pub mod exchange_model;
+mod offer_tracker;
+mod order_book;
@@ -0,0 +1,333 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use std::{collections::HashMap, fmt};
+
+use crate::models::{
+    account::PublicAccount,
+    mosaic::MosaicId,
+    transaction::{
+        AggregateTransaction, Amount, ExchangeOfferTransaction, HashValue, Height,
+        RemoveExchangeOfferTransaction, Transaction,
+    },
+    AssetId, Uint64,
+};
+
+use super::{OfferInfo, OfferType, UserExchangeInfo};
+
+/// What happened to a tracked offer.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OfferEventType {
+    /// A new offer was found in a snapshot.
+    Added,
+    /// Part of the offer was filled, it still has a remaining amount.
+    PartiallyFilled,
+    /// The whole remaining amount of the offer was filled.
+    Filled,
+    /// The offer reached its deadline.
+    Expired,
+    /// The offer was removed before its deadline.
+    Removed,
+}
+
+/// A change of one of the offers of the tracked account.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OfferEvent {
+    pub event_type: OfferEventType,
+    pub offer_type: OfferType,
+    pub mosaic_id: MosaicId,
+    /// The account that filled the offer, when it is known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub counterparty: Option<PublicAccount>,
+    /// The amount filled, or the remaining amount for any other event.
+    pub amount: Amount,
+    /// The cost of [amount] at the price of the offer.
+    pub cost: Amount,
+    /// The amount of the offer left after the event.
+    pub remaining: Amount,
+    /// The height of the transaction that caused the event, when it is known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<Height>,
+    /// The hash of the transaction that caused the event, when it is known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_hash: Option<HashValue>,
+}
+
+impl fmt::Display for OfferEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+/// Follows the exchange offers of an account over time.
+///
+/// The tracker is fed with the confirmed transactions of the account, which tell who
+/// filled an offer, and with successive [UserExchangeInfo] snapshots, which catch the
+/// changes no transaction was received for.
+#[derive(Debug)]
+pub struct OfferTracker {
+    owner: PublicAccount,
+    offers: HashMap<(OfferType, u64), OfferInfo>,
+}
+
+impl OfferTracker {
+    /// Creates a tracker for the offers of the given snapshot.
+    pub fn new(snapshot: &UserExchangeInfo) -> Self {
+        OfferTracker {
+            owner: snapshot.owner,
+            offers: Self::offers_of(snapshot),
+        }
+    }
+
+    pub fn owner(&self) -> PublicAccount {
+        self.owner
+    }
+
+    /// Returns the tracked offer of the given type for the mosaic.
+    pub fn offer(&self, offer_type: OfferType, mosaic_id: MosaicId) -> Option<&OfferInfo> {
+        self.offers.get(&(offer_type, mosaic_id.to_u64()))
+    }
+
+    /// Returns the number of tracked offers.
+    pub fn len(&self) -> usize {
+        self.offers.len()
+    }
+
+    /// Returns true if no offer is tracked.
+    pub fn is_empty(&self) -> bool {
+        self.offers.is_empty()
+    }
+
+    fn offers_of(snapshot: &UserExchangeInfo) -> HashMap<(OfferType, u64), OfferInfo> {
+        let mut offers = HashMap::new();
+        for (offer_type, offer_infos) in snapshot.offers.iter() {
+            for info in offer_infos.iter() {
+                offers.insert(
+                    (*offer_type, info.mosaic_id.to_u64()),
+                    info.offer_info.clone(),
+                );
+            }
+        }
+        offers
+    }
+
+    /// Updates the tracked offers with a confirmed transaction and returns the events
+    /// it caused. Transactions that do not touch the tracked offers cause no event.
+    ///
+    /// Exchange offer transactions filling the offers of the account, and remove
+    /// exchange offer transactions signed by it, are taken into account, including the
+    /// ones inside an aggregate.
+    pub fn apply_transaction(&mut self, transaction: &dyn Transaction) -> Vec<OfferEvent> {
+        let abs_transaction = transaction.abs_transaction();
+        let info = abs_transaction.transaction_info.as_ref();
+        let height = info.map(|info| info.height);
+        let hash = info.and_then(|info| info.hash.or(info.aggregate_hash));
+
+        let mut events = vec![];
+
+        if let Some(aggregate) = transaction.try_downcast_ref::<AggregateTransaction>() {
+            for inner in aggregate.inner_transactions.iter() {
+                events.extend(self.apply_inner(inner.as_ref(), height, hash));
+            }
+        } else {
+            events.extend(self.apply_inner(transaction, height, hash));
+        }
+
+        events
+    }
+
+    fn apply_inner(
+        &mut self,
+        transaction: &dyn Transaction,
+        height: Option<Height>,
+        hash: Option<HashValue>,
+    ) -> Vec<OfferEvent> {
+        let signer = transaction.abs_transaction().signer;
+
+        let mut events = vec![];
+
+        if let Some(exchange) = transaction.try_downcast_ref::<ExchangeOfferTransaction>() {
+            for confirmation in exchange.confirmations.iter() {
+                if confirmation.owner.public_key != self.owner.public_key {
+                    continue;
+                }
+
+                let offer = &confirmation.offer;
+                let key = (offer.r#type, offer.mosaic.asset_id.to_u64());
+
+                let tracked = match self.offers.get_mut(&key) {
+                    Some(tracked) => tracked,
+                    None => continue,
+                };
+
+                let remaining = tracked.mosaic.amount.saturating_sub(*offer.mosaic.amount);
+                tracked.mosaic.amount = Uint64::new(remaining);
+
+                events.push(OfferEvent {
+                    event_type: if remaining == 0 {
+                        OfferEventType::Filled
+                    } else {
+                        OfferEventType::PartiallyFilled
+                    },
+                    offer_type: offer.r#type,
+                    mosaic_id: MosaicId::from(key.1),
+                    counterparty: Some(signer),
+                    amount: offer.mosaic.amount,
+                    cost: offer.cost,
+                    remaining: Uint64::new(remaining),
+                    height,
+                    transaction_hash: hash,
+                });
+
+                if remaining == 0 {
+                    self.offers.remove(&key);
+                }
+            }
+        }
+
+        if let Some(remove) = transaction.try_downcast_ref::<RemoveExchangeOfferTransaction>() {
+            if signer.public_key != self.owner.public_key {
+                return events;
+            }
+
+            for removed in remove.offers.iter() {
+                let key = (removed.r#type, removed.asset_id.to_u64());
+                if let Some(offer) = self.offers.remove(&key) {
+                    events.push(Self::closed_event(
+                        OfferEventType::Removed,
+                        key,
+                        &offer,
+                        height,
+                        hash,
+                    ));
+                }
+            }
+        }
+
+        events
+    }
+
+    /// Stops tracking the offers that are expired at the given height and returns an
+    /// [OfferEventType::Expired] event for each of them.
+    pub fn expire_at(&mut self, height: Height) -> Vec<OfferEvent> {
+        let expired: Vec<(OfferType, u64)> = self
+            .offers
+            .iter()
+            .filter(|(_, offer)| offer.is_expired_at(height))
+            .map(|(key, _)| *key)
+            .collect();
+
+        expired
+            .into_iter()
+            .filter_map(|key| {
+                let offer = self.offers.remove(&key)?;
+                Some(Self::closed_event(
+                    OfferEventType::Expired,
+                    key,
+                    &offer,
+                    None,
+                    None,
+                ))
+            })
+            .collect()
+    }
+
+    /// Replaces the tracked offers with a newer snapshot taken at [height] and returns
+    /// the changes between them.
+    ///
+    /// Fills found this way have no counterparty. An offer that is gone before its
+    /// deadline is reported as removed, since the snapshot can not tell a removal from
+    /// a fill that was not received as a transaction.
+    pub fn apply_snapshot(
+        &mut self,
+        snapshot: &UserExchangeInfo,
+        height: Height,
+    ) -> Vec<OfferEvent> {
+        let mut current = Self::offers_of(snapshot);
+
+        let mut events = vec![];
+
+        for (key, previous) in self.offers.iter() {
+            match current.get(key) {
+                Some(offer) if *offer.mosaic.amount < *previous.mosaic.amount => {
+                    let filled = Uint64::new(*previous.mosaic.amount - *offer.mosaic.amount);
+
+                    events.push(OfferEvent {
+                        event_type: if *offer.mosaic.amount == 0 {
+                            OfferEventType::Filled
+                        } else {
+                            OfferEventType::PartiallyFilled
+                        },
+                        offer_type: key.0,
+                        mosaic_id: MosaicId::from(key.1),
+                        counterparty: None,
+                        amount: filled,
+                        cost: previous.cost(key.0, filled),
+                        remaining: offer.mosaic.amount,
+                        height: None,
+                        transaction_hash: None,
+                    });
+                }
+                Some(_) => (),
+                None => {
+                    let event_type = if previous.is_expired_at(height) {
+                        OfferEventType::Expired
+                    } else {
+                        OfferEventType::Removed
+                    };
+                    events.push(Self::closed_event(event_type, *key, previous, None, None));
+                }
+            }
+        }
+
+        for (key, offer) in current.iter() {
+            if !self.offers.contains_key(key) {
+                events.push(OfferEvent {
+                    event_type: OfferEventType::Added,
+                    offer_type: key.0,
+                    mosaic_id: MosaicId::from(key.1),
+                    counterparty: None,
+                    amount: offer.mosaic.amount,
+                    cost: offer.cost(key.0, offer.mosaic.amount),
+                    remaining: offer.mosaic.amount,
+                    height: None,
+                    transaction_hash: None,
+                });
+            }
+        }
+
+        current.retain(|_, offer| *offer.mosaic.amount > 0);
+        self.offers = current;
+
+        events
+    }
+
+    fn closed_event(
+        event_type: OfferEventType,
+        key: (OfferType, u64),
+        offer: &OfferInfo,
+        height: Option<Height>,
+        hash: Option<HashValue>,
+    ) -> OfferEvent {
+        OfferEvent {
+            event_type,
+            offer_type: key.0,
+            mosaic_id: MosaicId::from(key.1),
+            counterparty: None,
+            amount: offer.mosaic.amount,
+            cost: offer.cost(key.0, offer.mosaic.amount),
+            remaining: Uint64::default(),
+            height,
+            transaction_hash: hash,
+        }
+    }
+}
@@ -0,0 +1,218 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use std::{cmp::Ordering, fmt};
+
+use crate::{
+    models::{
+        errors_const,
+        mosaic::{Mosaic, MosaicId},
+        network::NetworkType,
+        transaction::{Amount, Deadline, ExchangeOfferTransaction, Height},
+        Uint64,
+    },
+    Result,
+};
+
+use super::{ExchangeConfirmation, Offer, OfferInfo, OfferInfos, OfferType};
+
+/// The offers of one side of an [OrderBook] that share the same price.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceLevel {
+    pub price_numerator: Uint64,
+    pub price_denominator: Uint64,
+    /// The remaining amount of all the offers at this price.
+    pub amount: Amount,
+    /// The number of offers at this price.
+    pub offers: usize,
+}
+
+impl PriceLevel {
+    /// Returns the price of one unit of the mosaic, as a floating point number.
+    pub fn price(&self) -> f64 {
+        if *self.price_denominator == 0 {
+            return 0.0;
+        }
+
+        *self.price_numerator as f64 / *self.price_denominator as f64
+    }
+}
+
+/// The buy and sell offers of a mosaic, sorted from the best price to the worst.
+///
+/// Buy offers (bids) are sorted by descending price, and sell offers (asks) by
+/// ascending price.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderBook {
+    pub mosaic_id: MosaicId,
+    pub bids: OfferInfos,
+    pub asks: OfferInfos,
+}
+
+impl OrderBook {
+    pub fn new(mosaic_id: MosaicId, buy_offers: OfferInfos, sell_offers: OfferInfos) -> Self {
+        let mut bids = buy_offers;
+        bids.sort_by(|a, b| b.cmp_price(a));
+
+        let mut asks = sell_offers;
+        asks.sort_by(|a, b| a.cmp_price(b));
+
+        OrderBook {
+            mosaic_id,
+            bids,
+            asks,
+        }
+    }
+
+    /// Returns the offers of the given type.
+    pub fn offers(&self, offer_type: OfferType) -> &[OfferInfo] {
+        match offer_type {
+            OfferType::BuyOffer => &self.bids,
+            OfferType::SellOffer => &self.asks,
+            _ => &[],
+        }
+    }
+
+    /// Returns the buy offer with the highest price.
+    pub fn best_bid(&self) -> Option<&OfferInfo> {
+        self.bids.first()
+    }
+
+    /// Returns the sell offer with the lowest price.
+    pub fn best_ask(&self) -> Option<&OfferInfo> {
+        self.asks.first()
+    }
+
+    /// Returns the difference between the best ask and the best bid prices.
+    pub fn spread(&self) -> Option<f64> {
+        Some(self.best_ask()?.price() - self.best_bid()?.price())
+    }
+
+    /// Returns the remaining amount of all the offers of the given type.
+    pub fn depth(&self, offer_type: OfferType) -> Amount {
+        Uint64::new(self.offers(offer_type).iter().fold(0u64, |total, offer| {
+            total.saturating_add(*offer.mosaic.amount)
+        }))
+    }
+
+    /// Aggregates the offers of the given type by price, from the best price to the worst.
+    pub fn price_levels(&self, offer_type: OfferType) -> Vec<PriceLevel> {
+        let mut levels: Vec<PriceLevel> = vec![];
+        let mut last: Option<&OfferInfo> = None;
+
+        for offer in self.offers(offer_type) {
+            match (last, levels.last_mut()) {
+                (Some(prev), Some(level)) if prev.cmp_price(offer) == Ordering::Equal => {
+                    level.amount = Uint64::new(level.amount.saturating_add(*offer.mosaic.amount));
+                    level.offers += 1;
+                }
+                _ => levels.push(PriceLevel {
+                    price_numerator: offer.price_numerator,
+                    price_denominator: offer.price_denominator,
+                    amount: offer.mosaic.amount,
+                    offers: 1,
+                }),
+            }
+            last = Some(offer);
+        }
+
+        levels
+    }
+
+    /// Returns the order book without the offers that are expired at the given height.
+    pub fn active_at(&self, height: Height) -> Self {
+        let active = |offers: &OfferInfos| -> OfferInfos {
+            offers
+                .iter()
+                .filter(|offer| !offer.is_expired_at(height))
+                .cloned()
+                .collect()
+        };
+
+        OrderBook {
+            mosaic_id: self.mosaic_id,
+            bids: active(&self.bids),
+            asks: active(&self.asks),
+        }
+    }
+
+    /// Matches [amount] units of the mosaic against the best offers of the given type
+    /// that are still active at [height].
+    ///
+    /// Fill sell offers to buy the mosaic, and buy offers to sell it. Every offer is
+    /// filled up to its remaining amount.
+    pub fn match_offers(
+        &self,
+        offer_type: OfferType,
+        amount: Amount,
+        height: Height,
+    ) -> Result<Vec<ExchangeConfirmation>> {
+        ensure!(
+            offer_type != OfferType::UnknownType,
+            errors_const::ERR_INVALID_EXCHANGE_OFFER_TYPE
+        );
+
+        let mut remaining = *amount;
+        let mut confirmations = vec![];
+
+        for offer in self.offers(offer_type) {
+            if remaining == 0 {
+                break;
+            }
+
+            if offer.is_expired_at(height) || *offer.mosaic.amount == 0 {
+                continue;
+            }
+
+            let fill = Uint64::new(remaining.min(*offer.mosaic.amount));
+
+            confirmations.push(ExchangeConfirmation::new(
+                Offer::new(
+                    offer_type,
+                    Mosaic::new(self.mosaic_id, *fill),
+                    offer.cost(offer_type, fill),
+                ),
+                offer.owner,
+            ));
+
+            remaining -= *fill;
+        }
+
+        ensure!(
+            remaining == 0,
+            errors_const::ERR_INSUFFICIENT_EXCHANGE_OFFERS
+        );
+
+        Ok(confirmations)
+    }
+
+    /// Creates the [ExchangeOfferTransaction] that fills [amount] units of the mosaic
+    /// against the best offers of the given type that are still active at [height].
+    pub fn create_fill_transaction(
+        &self,
+        deadline: Deadline,
+        offer_type: OfferType,
+        amount: Amount,
+        height: Height,
+        network_type: NetworkType,
+    ) -> Result<ExchangeOfferTransaction> {
+        let confirmations = self.match_offers(offer_type, amount, height)?;
+
+        ExchangeOfferTransaction::new(deadline, confirmations, network_type)
+    }
+}
+
+impl fmt::Display for OrderBook {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
@@ -0,0 +1,26 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::helpers::hex_encode;
+
+/// Serializes a binary message payload as a hex string.
+pub(super) fn serialize_payload<S>(payload: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&hex_encode(payload))
+}
+
+/// Deserializes a binary message payload from a hex string.
+pub(super) fn deserialize_payload<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let payload = String::deserialize(deserializer)?;
+    hex::decode(&payload).map_err(serde::de::Error::custom)
+}
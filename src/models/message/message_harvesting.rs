@@ -0,0 +1,126 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use crate::{
+    crypto::{Ed25519BlockCipher, PublicKey},
+    errors_const,
+    helpers::hex_encode,
+    models::{
+        account::{Account, PublicAccount},
+        consts::KEY_SIZE,
+        network::NetworkType,
+    },
+    Result,
+};
+
+use super::{
+    internally::{deserialize_payload, serialize_payload},
+    Message, MessageType, SecureMessageError,
+};
+
+/// A message that hands the private key of a remote account over to a node, so the node
+/// can harvest on behalf of the account.
+///
+/// The private key is encrypted with a one-time ephemeral key pair and the node public key.
+/// The payload layout is: ephemeral public key (32 bytes) | encrypted private key.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PersistentHarvestingDelegationMessage {
+    #[serde(rename = "type")]
+    _type: MessageType,
+    #[serde(
+        serialize_with = "serialize_payload",
+        deserialize_with = "deserialize_payload"
+    )]
+    pub payload: Vec<u8>,
+}
+
+impl PersistentHarvestingDelegationMessage {
+    /// Creates a delegated harvesting message for the [remote_account], readable only by
+    /// the node owning the [recipient_public_account].
+    pub fn create(
+        remote_account: &Account,
+        recipient_public_account: &PublicAccount,
+    ) -> Result<Self> {
+        let ephemeral_account = Account::new(NetworkType::default());
+
+        let mut block_cipher = Ed25519BlockCipher::new(&ephemeral_account.key_pair.secret);
+
+        let encrypted_key = block_cipher.encrypt(
+            &remote_account.key_pair.secret.to_bytes(),
+            &PublicKey::from_bytes(recipient_public_account.to_bytes())?,
+        );
+
+        let mut payload = ephemeral_account.public_account.to_bytes().to_vec();
+        payload.extend_from_slice(&encrypted_key);
+
+        Ok(PersistentHarvestingDelegationMessage {
+            _type: MessageType::PersistentHarvestingDelegationMessageType,
+            payload,
+        })
+    }
+
+    /// Creates a delegated harvesting message from an already encrypted [payload].
+    pub fn from_bytes(payload: &[u8]) -> Result<Self> {
+        ensure!(
+            payload.len() > KEY_SIZE,
+            errors_const::ERR_INVALID_HARVESTING_MESSAGE_LENGTH
+        );
+
+        Ok(PersistentHarvestingDelegationMessage {
+            _type: MessageType::PersistentHarvestingDelegationMessageType,
+            payload: payload.to_vec(),
+        })
+    }
+
+    /// Decrypts the message with the node [recipient_account].
+    ///
+    /// Returns the private key of the remote account formatted as hexadecimal.
+    pub fn decrypt(
+        &self,
+        recipient_account: &Account,
+    ) -> ::std::result::Result<String, SecureMessageError> {
+        if self.payload.len() <= KEY_SIZE {
+            return Err(SecureMessageError::InvalidPayload);
+        }
+
+        let (ephemeral_key, encrypted_key) = self.payload.split_at(KEY_SIZE);
+
+        let ephemeral_public_key = PublicKey::from_bytes(ephemeral_key)
+            .map_err(|_| SecureMessageError::InvalidPublicKey)?;
+
+        let mut block_cipher = Ed25519BlockCipher::new(&recipient_account.key_pair.secret);
+
+        let private_key = block_cipher
+            .decrypt(encrypted_key, &ephemeral_public_key)
+            .map_err(|_| SecureMessageError::DecryptionFailed)?;
+
+        Ok(hex_encode(&private_key))
+    }
+}
+
+impl Message for PersistentHarvestingDelegationMessage {
+    fn message_type(&self) -> &MessageType {
+        &self._type
+    }
+
+    fn payload_to_bytes(&self) -> &[u8] {
+        &self.payload
+    }
+
+    fn box_clone(&self) -> Box<dyn Message + 'static> {
+        Box::new((*self).clone())
+    }
+}
+
+impl core::fmt::Display for PersistentHarvestingDelegationMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
@@ -14,6 +14,12 @@ where
 {
     fn message_type(&self) -> &MessageType;
 
+    /// The type byte written on chain, which may differ from `message_type` for a
+    /// message of an unknown type.
+    fn message_type_value(&self) -> u8 {
+        self.message_type().value()
+    }
+
     fn payload_to_bytes(&self) -> &[u8];
 
     fn box_clone(&self) -> Box<dyn Message>;
@@ -0,0 +1,72 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use crate::{errors_const, helpers::hex_encode, Result};
+
+use super::{
+    internally::{deserialize_payload, serialize_payload},
+    Message, MessageType,
+};
+
+/// An unencrypted message carrying arbitrary bytes, like a hash or a serialized reference.
+///
+/// On chain it is a regular plain message, only the payload is not required to be UTF-8 text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RawMessage {
+    #[serde(rename = "type")]
+    _type: MessageType,
+    #[serde(
+        serialize_with = "serialize_payload",
+        deserialize_with = "deserialize_payload"
+    )]
+    pub payload: Vec<u8>,
+}
+
+impl RawMessage {
+    pub fn new(payload: &[u8]) -> Self {
+        RawMessage {
+            _type: MessageType::PlainMessageType,
+            payload: payload.to_vec(),
+        }
+    }
+
+    /// Creates a raw message from a hex encoded [payload].
+    pub fn from_hex(payload: &str) -> Result<Self> {
+        let payload = hex::decode(payload)
+            .map_err(|_| format_err!("{}", errors_const::ERR_INVALID_PAYLOAD_HEX))?;
+
+        Ok(Self::new(&payload))
+    }
+
+    /// The payload formatted as hexadecimal.
+    pub fn payload_to_hex(&self) -> String {
+        hex_encode(&self.payload)
+    }
+}
+
+impl Message for RawMessage {
+    fn message_type(&self) -> &MessageType {
+        &self._type
+    }
+
+    fn payload_to_bytes(&self) -> &[u8] {
+        &self.payload
+    }
+
+    fn box_clone(&self) -> Box<dyn Message + 'static> {
+        Box::new((*self).clone())
+    }
+}
+
+impl core::fmt::Display for RawMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
@@ -8,7 +8,7 @@ use super::{Message, MessageType};
 use crate::{
     crypto::{Ed25519BlockCipher, PublicKey},
     errors_const,
-    helpers::{hex_decode, hex_encode, is_hex},
+    helpers::hex_encode,
     models::{
         account::{Account, PublicAccount},
         message::PlainMessage,
@@ -16,7 +16,31 @@ use crate::{
     Result,
 };
 
-use std::borrow::Borrow;
+use std::{borrow::Borrow, fmt};
+
+/// The error returned when a [`SecureMessage`] can not be decrypted.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SecureMessageError {
+    /// The encrypted payload is not a hex encoded string.
+    InvalidPayload,
+    /// The public key of the sender is not valid.
+    InvalidPublicKey,
+    /// The payload could not be decrypted, either the keys are wrong or the payload is corrupt.
+    DecryptionFailed,
+}
+
+impl fmt::Display for SecureMessageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use SecureMessageError::*;
+        match *self {
+            InvalidPayload => write!(f, "{}", errors_const::ERR_INVALID_PAYLOAD_HEX),
+            InvalidPublicKey => write!(f, "{}", errors_const::ERR_INVALID_KEY_HEX),
+            DecryptionFailed => write!(f, "{}", errors_const::ERR_SECURE_MESSAGE_DECRYPTION),
+        }
+    }
+}
+
+impl ::failure::Fail for SecureMessageError {}
 
 /// An encrypted message model defines a secure message that has been encrypted using
 /// the Sirius's SDK libraries.
@@ -37,27 +61,41 @@ pub struct SecureMessage {
 }
 
 impl SecureMessage {
-    /// Creates an encrypted message from [plainTextMessage].
+    /// Creates an encrypted message from the UTF-8 text [payload].
     ///
     /// The message is encrypted using a shared key generated by the [senderPrivateKey] and
     /// the [recipient_public_key]. The recipient can decrypt this message using this shared key by
     /// taking the sender's public key and the recipient's public key.
+    ///
+    /// The payload is always treated as text, use `from_bytes` to encrypt binary data.
     pub fn create(
         sender_account: &Account,
         recipient_public_account: &PublicAccount,
         payload: &str,
+    ) -> Result<Self> {
+        Self::from_text(sender_account, recipient_public_account, payload)
+    }
+
+    /// Creates an encrypted message from the UTF-8 text [payload].
+    pub fn from_text(
+        sender_account: &Account,
+        recipient_public_account: &PublicAccount,
+        payload: &str,
+    ) -> Result<Self> {
+        Self::from_bytes(sender_account, recipient_public_account, payload.as_bytes())
+    }
+
+    /// Creates an encrypted message from the raw bytes [payload].
+    pub fn from_bytes(
+        sender_account: &Account,
+        recipient_public_account: &PublicAccount,
+        payload: &[u8],
     ) -> Result<Self> {
         // Encrypts the message
         let mut block_cipher = Ed25519BlockCipher::new(&sender_account.key_pair.secret);
 
-        let payload_vec = if is_hex(&payload) {
-            hex_decode(payload)
-        } else {
-            payload.as_bytes().to_vec()
-        };
-
         let encrypted_payload = block_cipher.encrypt(
-            &payload_vec,
+            payload,
             &PublicKey::from_bytes(recipient_public_account.to_bytes())?,
         );
 
@@ -71,7 +109,10 @@ impl SecureMessage {
     ///
     /// The [payload] is a hex encoded string.
     pub fn from_hex_payload(payload: &str) -> Result<Self> {
-        ensure!(is_hex(payload), errors_const::ERR_INVALID_PAYLOAD_HEX);
+        ensure!(
+            hex::decode(payload).is_ok(),
+            errors_const::ERR_INVALID_PAYLOAD_HEX
+        );
 
         let msg_s = SecureMessage {
             _type: MessageType::SecureMessageType,
@@ -82,24 +123,38 @@ impl SecureMessage {
 
     /// Decrypts an [encryptedMessage].
     ///
-    /// Returns a PlainMessage.
-    pub fn decrypt(
+    /// Returns the decrypted bytes.
+    pub fn decrypt_to_bytes(
         &self,
         recipient_account: &Account,
         sender_public_account: &PublicAccount,
-    ) -> Result<PlainMessage> {
+    ) -> ::std::result::Result<Vec<u8>, SecureMessageError> {
+        let payload = hex::decode(&self.payload).map_err(|_| SecureMessageError::InvalidPayload)?;
+
+        let sender_public_key = PublicKey::from_bytes(sender_public_account.to_bytes())
+            .map_err(|_| SecureMessageError::InvalidPublicKey)?;
+
         let mut block_cipher = Ed25519BlockCipher::new(&recipient_account.key_pair.secret);
 
-        let encrypted_payload = block_cipher.decrypt(
-            &hex_decode(&self.payload),
-            &PublicKey::from_bytes(sender_public_account.to_bytes())?,
-        );
+        block_cipher
+            .decrypt(&payload, &sender_public_key)
+            .map_err(|_| SecureMessageError::DecryptionFailed)
+    }
 
-        let encrypted_payload = encrypted_payload.unwrap();
+    /// Decrypts an [encryptedMessage].
+    ///
+    /// Returns a PlainMessage. A payload that is not valid UTF-8 is returned hex encoded,
+    /// use `decrypt_to_bytes` to get the raw bytes back.
+    pub fn decrypt(
+        &self,
+        recipient_account: &Account,
+        sender_public_account: &PublicAccount,
+    ) -> ::std::result::Result<PlainMessage, SecureMessageError> {
+        let decrypted_payload = self.decrypt_to_bytes(recipient_account, sender_public_account)?;
 
-        let payload = match String::from_utf8(encrypted_payload.to_owned()) {
+        let payload = match String::from_utf8(decrypted_payload) {
             Ok(payload) => payload,
-            Err(_) => hex_encode(&encrypted_payload),
+            Err(err) => hex_encode(err.as_bytes()),
         };
 
         Ok(PlainMessage::new(&payload))
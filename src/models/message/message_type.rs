@@ -9,6 +9,7 @@ use {num_enum::IntoPrimitive, std::fmt};
 /// The type of the message:
 /// * 0 - Plain text or unencrypted message.
 /// * 1 - Secured text or encrypted message.
+/// * 254 - Persistent delegated harvesting message.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Copy, IntoPrimitive)]
 #[repr(u8)]
 pub enum MessageType {
@@ -18,6 +19,9 @@ pub enum MessageType {
     /// Secured text or encrypted message.
     #[serde(rename = "1")]
     SecureMessageType = 0x01,
+    /// Encrypted private key of a remote account delegated to a node for harvesting.
+    #[serde(rename = "254")]
+    PersistentHarvestingDelegationMessageType = 0xFE,
     UnknownMessageType,
 }
 
@@ -33,6 +37,9 @@ impl fmt::Display for MessageType {
         match *self {
             PlainMessageType => write!(f, "PlainMessageType"),
             SecureMessageType => write!(f, "SecureMessageType"),
+            PersistentHarvestingDelegationMessageType => {
+                write!(f, "PersistentHarvestingDelegationMessageType")
+            }
             UnknownMessageType => write!(f, "UnknownMessageType"),
         }
     }
@@ -46,6 +53,7 @@ impl From<u8> for MessageType {
         match num {
             0x00 => MessageType::PlainMessageType,
             0x01 => MessageType::SecureMessageType,
+            0xFE => MessageType::PersistentHarvestingDelegationMessageType,
             _ => MessageType::UnknownMessageType,
         }
     }
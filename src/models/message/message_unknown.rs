@@ -0,0 +1,69 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use crate::helpers::hex_encode;
+
+use super::{
+    internally::{deserialize_payload, serialize_payload},
+    Message, MessageType,
+};
+
+/// A message of a type this SDK does not know about.
+///
+/// The original type and payload are kept as they are, so the message is serialized back
+/// exactly as it was received.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnknownMessage {
+    #[serde(rename = "type")]
+    pub raw_type: u8,
+    #[serde(
+        serialize_with = "serialize_payload",
+        deserialize_with = "deserialize_payload"
+    )]
+    pub payload: Vec<u8>,
+}
+
+impl UnknownMessage {
+    pub fn new(raw_type: u8, payload: &[u8]) -> Self {
+        UnknownMessage {
+            raw_type,
+            payload: payload.to_vec(),
+        }
+    }
+
+    /// The payload formatted as hexadecimal.
+    pub fn payload_to_hex(&self) -> String {
+        hex_encode(&self.payload)
+    }
+}
+
+impl Message for UnknownMessage {
+    fn message_type(&self) -> &MessageType {
+        &MessageType::UnknownMessageType
+    }
+
+    fn message_type_value(&self) -> u8 {
+        self.raw_type
+    }
+
+    fn payload_to_bytes(&self) -> &[u8] {
+        &self.payload
+    }
+
+    fn box_clone(&self) -> Box<dyn Message + 'static> {
+        Box::new((*self).clone())
+    }
+}
+
+impl core::fmt::Display for UnknownMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
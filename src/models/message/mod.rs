@@ -4,12 +4,19 @@
  * license that can be found in the LICENSE file.
  */
 
+pub use self::message_harvesting::*;
 pub use self::message_model::*;
 pub use self::message_plain::*;
+pub use self::message_raw::*;
 pub use self::message_secure::*;
 pub use self::message_type::*;
+pub use self::message_unknown::*;
 
+mod internally;
+mod message_harvesting;
 mod message_model;
 mod message_plain;
+mod message_raw;
 mod message_secure;
 mod message_type;
+mod message_unknown;
@@ -11,9 +11,16 @@ use {
 };
 
 use crate::models::{
-    account::Address, consts::SIZE_SIZE, mosaic::MosaicId, namespace::NamespaceId, AssetId,
+    account::Address,
+    consts::{METADATA_MAX_KEY_SIZE, METADATA_MAX_VALUE_SIZE, SIZE_SIZE},
+    errors_const,
+    mosaic::MosaicId,
+    namespace::NamespaceId,
+    AssetId,
 };
 
+use super::metadata_modifications;
+
 ///The type of the metadata:
 ///* 1 - Address metadata.
 ///* 2 - Mosaic metadata.
@@ -111,6 +118,23 @@ pub struct MetadataInfo {
     pub fields: HashMap<String, String>,
 }
 
+impl MetadataInfo {
+    /// Returns the value of the given key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields.get(key).map(String::as_str)
+    }
+
+    /// Returns the minimal modifications that turn these fields into [desired].
+    ///
+    /// See [metadata_modifications].
+    pub fn diff(
+        &self,
+        desired: &HashMap<String, String>,
+    ) -> crate::Result<Vec<MetadataModification>> {
+        metadata_modifications(&self.fields, desired)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AddressMetadataInfo {
     pub info: MetadataInfo,
@@ -229,6 +253,27 @@ impl MetadataModification {
         }
     }
 
+    /// Checks the key and value sizes against the limits of the network.
+    ///
+    /// The value of a remove modification is not stored, so only its key is checked.
+    pub fn validate(&self) -> crate::Result<()> {
+        ensure!(!self.key.is_empty(), errors_const::ERR_METADATA_EMPTY_KEY);
+
+        ensure!(
+            self.key.len() <= METADATA_MAX_KEY_SIZE,
+            errors_const::ERR_METADATA_KEY_TOO_LONG
+        );
+
+        if self.r#type == MetadataModificationType::Add {
+            ensure!(
+                self.value.len() <= METADATA_MAX_VALUE_SIZE,
+                errors_const::ERR_METADATA_VALUE_TOO_LONG
+            );
+        }
+
+        Ok(())
+    }
+
     pub fn size(&self) -> usize {
         SIZE_SIZE
             + 1 /* MetadataModificationType size */
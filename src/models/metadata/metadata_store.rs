@@ -0,0 +1,166 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    models::{
+        account::Address,
+        consts::METADATA_MAX_FIELDS,
+        errors_const,
+        mosaic::MosaicId,
+        namespace::NamespaceId,
+        network::NetworkType,
+        transaction::{
+            Deadline, MetadataAddressTransaction, MetadataMosaicTransaction,
+            MetadataNamespaceTransaction, Transaction,
+        },
+    },
+    Result,
+};
+
+use super::{MetadataModification, MetadataModificationType, MetadataType};
+
+/// The account, mosaic or namespace metadata is attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MetadataTarget {
+    Address(Address),
+    Mosaic(MosaicId),
+    Namespace(NamespaceId),
+}
+
+impl MetadataTarget {
+    pub fn metadata_type(&self) -> MetadataType {
+        match self {
+            MetadataTarget::Address(_) => MetadataType::MetadataAddressType,
+            MetadataTarget::Mosaic(_) => MetadataType::MetadataMosaicType,
+            MetadataTarget::Namespace(_) => MetadataType::MetadataNamespaceType,
+        }
+    }
+
+    /// Creates the modify metadata transaction of this target applying [modifications].
+    pub fn create_transaction(
+        &self,
+        deadline: Deadline,
+        modifications: Vec<MetadataModification>,
+        network_type: NetworkType,
+    ) -> Result<Box<dyn Transaction>> {
+        Ok(match *self {
+            MetadataTarget::Address(address) => Box::new(MetadataAddressTransaction::new(
+                deadline,
+                address,
+                modifications,
+                network_type,
+            )?),
+            MetadataTarget::Mosaic(mosaic_id) => Box::new(MetadataMosaicTransaction::new(
+                deadline,
+                mosaic_id,
+                modifications,
+                network_type,
+            )?),
+            MetadataTarget::Namespace(namespace_id) => Box::new(MetadataNamespaceTransaction::new(
+                deadline,
+                namespace_id,
+                modifications,
+                network_type,
+            )?),
+        })
+    }
+}
+
+impl From<Address> for MetadataTarget {
+    fn from(address: Address) -> Self {
+        MetadataTarget::Address(address)
+    }
+}
+
+impl From<MosaicId> for MetadataTarget {
+    fn from(mosaic_id: MosaicId) -> Self {
+        MetadataTarget::Mosaic(mosaic_id)
+    }
+}
+
+impl From<NamespaceId> for MetadataTarget {
+    fn from(namespace_id: NamespaceId) -> Self {
+        MetadataTarget::Namespace(namespace_id)
+    }
+}
+
+impl fmt::Display for MetadataTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MetadataTarget::Address(address) => write!(f, "{}", address.address_string()),
+            MetadataTarget::Mosaic(mosaic_id) => write!(f, "{}", mosaic_id),
+            MetadataTarget::Namespace(namespace_id) => write!(f, "{}", namespace_id),
+        }
+    }
+}
+
+/// Checks that [modifications] can be applied in a single transaction: every key and
+/// value fits the limits of the network and no key is modified twice, apart from a
+/// remove followed by an add that changes its value.
+pub fn validate_metadata_modifications(modifications: &[MetadataModification]) -> Result<()> {
+    ensure!(
+        !modifications.is_empty(),
+        errors_const::ERR_METADATA_EMPTY_MODIFICATIONS
+    );
+
+    let mut seen: HashMap<&str, MetadataModificationType> = HashMap::new();
+    for modification in modifications.iter() {
+        modification.validate()?;
+
+        if let Some(previous) = seen.insert(&modification.key, modification.r#type) {
+            ensure!(
+                previous == MetadataModificationType::Remove
+                    && modification.r#type == MetadataModificationType::Add,
+                errors_const::ERR_METADATA_DUPLICATE_KEY
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the minimal modifications that turn the [current] fields into the [desired]
+/// ones, or no modification when they are equal.
+///
+/// Keys that are not desired anymore are removed, and keys whose value changed are
+/// removed and added again with the new value. Removals come first, so the number of
+/// fields never goes over the limit while the transaction is applied.
+pub fn metadata_modifications(
+    current: &HashMap<String, String>,
+    desired: &HashMap<String, String>,
+) -> Result<Vec<MetadataModification>> {
+    ensure!(
+        desired.len() <= METADATA_MAX_FIELDS,
+        errors_const::ERR_METADATA_TOO_MANY_FIELDS
+    );
+
+    let mut removals: Vec<MetadataModification> = current
+        .iter()
+        .filter(|(key, value)| desired.get(*key) != Some(*value))
+        .map(|(key, _)| MetadataModification::new(MetadataModificationType::Remove, key, ""))
+        .collect();
+
+    let mut additions: Vec<MetadataModification> = desired
+        .iter()
+        .filter(|(key, value)| current.get(*key) != Some(*value))
+        .map(|(key, value)| MetadataModification::new(MetadataModificationType::Add, key, value))
+        .collect();
+
+    // Keep the order stable, so the same diff always builds the same transaction.
+    removals.sort_by(|a, b| a.key.cmp(&b.key));
+    additions.sort_by(|a, b| a.key.cmp(&b.key));
+
+    removals.append(&mut additions);
+
+    if !removals.is_empty() {
+        validate_metadata_modifications(&removals)?;
+    }
+
+    Ok(removals)
+}
@@ -5,5 +5,7 @@
  */
 
 pub use self::metadata_model::*;
+pub use self::metadata_store::*;
 
 mod metadata_model;
+mod metadata_store;
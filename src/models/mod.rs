@@ -6,6 +6,7 @@
 
 pub use self::asset_id_model::*;
 pub use self::error::Result;
+pub use self::roles_type_enum::*;
 pub use self::uint_64::Uint64;
 
 pub mod account;
@@ -21,6 +22,7 @@ pub mod multisig;
 pub mod namespace;
 pub mod network;
 pub mod node;
+pub mod receipt;
 pub mod transaction;
 
 mod asset_id_model;
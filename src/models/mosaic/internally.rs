@@ -21,7 +21,7 @@ pub(super) const XPX_MAX_RELATIVE_VALUE: u64 = 9_000_000_000;
 
 pub(super) const XPX_MIN_VALUE: u64 = 1;
 
-pub(super) const PRX_XPX_U64: u64 = 13_833_723_942_089_965_046;
+pub(crate) const PRX_XPX_U64: u64 = 13_833_723_942_089_965_046;
 
 pub(super) fn generate_mosaic_id(nonce: MosaicNonce, owner_public_id: PublicAccount) -> Uint64 {
     let mut hash = Sha3_256::default();
@@ -4,7 +4,9 @@
  * license that can be found in the LICENSE file.
  */
 
+pub(crate) use self::internally::PRX_XPX_U64;
 use self::internally::*;
+pub use self::mosaic_amount::*;
 pub use self::mosaic_id::*;
 pub use self::mosaic_info::*;
 pub use self::mosaic_model::*;
@@ -12,6 +14,7 @@ pub use self::mosaic_nonce::*;
 pub use self::mosaic_properties::*;
 
 mod internally;
+mod mosaic_amount;
 mod mosaic_id;
 mod mosaic_info;
 mod mosaic_model;
@@ -0,0 +1,189 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use ::std::{convert::TryFrom, fmt};
+
+use crate::{
+    models::{errors_const, Uint64},
+    Result,
+};
+
+/// The highest divisibility whose scale (10^19) still fits in a u64.
+pub const MAX_MOSAIC_DIVISIBILITY: u8 = 19;
+
+/// A mosaic amount together with the divisibility of its mosaic.
+///
+/// The amount is kept in the smallest units of the mosaic, so a `MosaicAmount` of `1500000`
+/// with a divisibility of 6 is `1.5` in relative units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", try_from = "MosaicAmountDto")]
+pub struct MosaicAmount {
+    amount: u64,
+    divisibility: u8,
+}
+
+/// The serialized form of a [MosaicAmount], checked by `MosaicAmount::new` when
+/// deserializing.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MosaicAmountDto {
+    amount: u64,
+    divisibility: u8,
+}
+
+impl TryFrom<MosaicAmountDto> for MosaicAmount {
+    type Error = failure::Error;
+
+    fn try_from(dto: MosaicAmountDto) -> Result<Self> {
+        MosaicAmount::new(dto.amount, dto.divisibility)
+    }
+}
+
+impl MosaicAmount {
+    /// Creates an amount of [amount] smallest units of a mosaic with the given [divisibility].
+    pub fn new(amount: u64, divisibility: u8) -> Result<Self> {
+        ensure!(
+            divisibility <= MAX_MOSAIC_DIVISIBILITY,
+            errors_const::ERR_INVALID_MOSAIC_DIVISIBILITY
+        );
+
+        Ok(MosaicAmount {
+            amount,
+            divisibility,
+        })
+    }
+
+    /// Parses a relative amount like `"12.345678"` for a mosaic with the given [divisibility].
+    ///
+    /// An amount with more decimal places than the [divisibility] is rejected rather than
+    /// rounded.
+    pub fn parse(relative: &str, divisibility: u8) -> Result<Self> {
+        ensure!(
+            divisibility <= MAX_MOSAIC_DIVISIBILITY,
+            errors_const::ERR_INVALID_MOSAIC_DIVISIBILITY
+        );
+
+        let (integer, fraction) = match relative.find('.') {
+            Some(index) => (&relative[..index], &relative[index + 1..]),
+            None => (relative, ""),
+        };
+
+        ensure!(
+            !integer.is_empty()
+                && integer.bytes().all(|b| b.is_ascii_digit())
+                && fraction.bytes().all(|b| b.is_ascii_digit())
+                && !(relative.contains('.') && fraction.is_empty()),
+            errors_const::ERR_INVALID_MOSAIC_AMOUNT
+        );
+
+        ensure!(
+            fraction.len() <= divisibility as usize,
+            errors_const::ERR_MOSAIC_AMOUNT_PRECISION
+        );
+
+        let integer: u64 = integer
+            .parse()
+            .map_err(|_| format_err!("{}", errors_const::ERR_MOSAIC_AMOUNT_OVERFLOW))?;
+
+        let fraction: u64 = if fraction.is_empty() {
+            0
+        } else {
+            let padding = 10u64.pow((divisibility as usize - fraction.len()) as u32);
+            fraction.parse::<u64>()? * padding
+        };
+
+        let amount = integer
+            .checked_mul(Self::scale_of(divisibility))
+            .and_then(|amount| amount.checked_add(fraction));
+
+        match amount {
+            Some(amount) => Self::new(amount, divisibility),
+            None => bail!(errors_const::ERR_MOSAIC_AMOUNT_OVERFLOW),
+        }
+    }
+
+    fn scale_of(divisibility: u8) -> u64 {
+        10u64.pow(u32::from(divisibility))
+    }
+
+    /// The amount in the smallest units of the mosaic.
+    pub fn amount(&self) -> Uint64 {
+        Uint64::new(self.amount)
+    }
+
+    pub fn divisibility(&self) -> u8 {
+        self.divisibility
+    }
+
+    /// The whole units of the amount.
+    pub fn integer_part(&self) -> u64 {
+        self.amount / Self::scale_of(self.divisibility)
+    }
+
+    /// The fractional units of the amount, in smallest units.
+    pub fn fractional_part(&self) -> u64 {
+        self.amount % Self::scale_of(self.divisibility)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.amount == 0
+    }
+
+    /// Adds two amounts of mosaics with the same divisibility.
+    pub fn checked_add(&self, other: &MosaicAmount) -> Result<Self> {
+        self.ensure_same_divisibility(other)?;
+
+        match self.amount.checked_add(other.amount) {
+            Some(amount) => Self::new(amount, self.divisibility),
+            None => bail!(errors_const::ERR_MOSAIC_AMOUNT_OVERFLOW),
+        }
+    }
+
+    /// Subtracts two amounts of mosaics with the same divisibility.
+    pub fn checked_sub(&self, other: &MosaicAmount) -> Result<Self> {
+        self.ensure_same_divisibility(other)?;
+
+        match self.amount.checked_sub(other.amount) {
+            Some(amount) => Self::new(amount, self.divisibility),
+            None => bail!(errors_const::ERR_MOSAIC_AMOUNT_OVERFLOW),
+        }
+    }
+
+    /// Multiplies the amount by a whole number.
+    pub fn checked_mul(&self, factor: u64) -> Result<Self> {
+        match self.amount.checked_mul(factor) {
+            Some(amount) => Self::new(amount, self.divisibility),
+            None => bail!(errors_const::ERR_MOSAIC_AMOUNT_OVERFLOW),
+        }
+    }
+
+    fn ensure_same_divisibility(&self, other: &MosaicAmount) -> Result<()> {
+        ensure!(
+            self.divisibility == other.divisibility,
+            errors_const::ERR_MOSAIC_DIVISIBILITY_MISMATCH
+        );
+        Ok(())
+    }
+}
+
+/// Formats the amount in relative units, with as many decimal places as the divisibility.
+///
+/// For example: 12.345678
+impl fmt::Display for MosaicAmount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.divisibility == 0 {
+            return write!(f, "{}", self.amount);
+        }
+
+        write!(
+            f,
+            "{}.{:0width$}",
+            self.integer_part(),
+            self.fractional_part(),
+            width = self.divisibility as usize
+        )
+    }
+}
@@ -6,9 +6,9 @@
 
 use std::fmt;
 
-use crate::models::Uint64;
+use crate::models::{transaction::Height, Uint64};
 
-use super::{MosaicId, MosaicProperties};
+use super::{MosaicAmount, MosaicId, MosaicProperties};
 
 /// Contains information about a mosaic.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -60,6 +60,28 @@ impl MosaicInfo {
     pub fn is_transferable(&self) -> bool {
         self.properties.transferable
     }
+
+    /// Returns the number of decimal places the mosaic can be divided into.
+    pub fn divisibility(&self) -> u8 {
+        self.properties.divisibility
+    }
+
+    /// Returns the height at which the mosaic expires, or `None` when the mosaic
+    /// does not expire.
+    pub fn end_height(&self) -> Option<Height> {
+        if *self.properties.duration == 0 {
+            return None;
+        }
+
+        Some(Uint64::new(
+            self.height.saturating_add(*self.properties.duration),
+        ))
+    }
+
+    /// Returns the total supply of the mosaic in relative units.
+    pub fn relative_supply(&self) -> crate::Result<MosaicAmount> {
+        MosaicAmount::new(*self.supply, self.divisibility())
+    }
 }
 
 impl fmt::Display for MosaicInfo {
@@ -15,7 +15,7 @@ use super::{
     internally::{
         PRX_XPX_U64, XPX_DIVISIBILITY, XPX_MAX_RELATIVE_VALUE, XPX_MAX_VALUE, XPX_MIN_VALUE,
     },
-    MosaicId,
+    MosaicAmount, MosaicId, MosaicInfo,
 };
 
 pub const SUPPLY_MUTABLE: u8 = 0x01;
@@ -100,6 +100,8 @@ impl Mosaic {
         }
     }
 
+    /// Creates a Mosaic of XPX, the currency of the ProximaX public networks. On networks
+    /// with another currency, use `NetworkProperties::currency`.
     pub fn xpx(amount: u64) -> Self {
         assert!(
             amount <= XPX_MAX_VALUE,
@@ -132,6 +134,27 @@ impl Mosaic {
 
         Mosaic::xpx(amount * XPX_DIVISIBILITY)
     }
+
+    /// Creates a new Mosaic with the given `Id` and a relative amount like `"1.5"`, converted
+    /// to smallest units with the divisibility of the given [MosaicInfo].
+    pub fn relative<T: AssetId + 'static>(
+        asset_id: T,
+        amount: &str,
+        mosaic_info: &MosaicInfo,
+    ) -> crate::Result<Self> {
+        let amount = MosaicAmount::parse(amount, mosaic_info.divisibility())?;
+
+        Ok(Self {
+            asset_id: Box::new(asset_id),
+            amount: amount.amount(),
+        })
+    }
+
+    /// Returns the amount of this mosaic in relative units, with the divisibility of the
+    /// given [MosaicInfo].
+    pub fn relative_amount(&self, mosaic_info: &MosaicInfo) -> crate::Result<MosaicAmount> {
+        MosaicAmount::new(*self.amount, mosaic_info.divisibility())
+    }
 }
 
 impl fmt::Display for Mosaic {
@@ -5,7 +5,11 @@
  */
 
 pub use self::cosignature::*;
+pub use self::multisig_builder::*;
+pub use self::multisig_graph::*;
 pub use self::multisig_model::*;
 
 mod cosignature;
+mod multisig_builder;
+mod multisig_graph;
 mod multisig_model;
@@ -0,0 +1,423 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+};
+
+use crate::{
+    models::{
+        account::PublicAccount,
+        consts::{
+            MULTISIG_MAX_COSIGNATORIES, MULTISIG_MAX_COSIGNED_ACCOUNTS, MULTISIG_MAX_DEPTH,
+            PUBLIC_KEY_BYTES_SIZE,
+        },
+        errors_const,
+        network::{NetworkProperties, NetworkType},
+        transaction::{
+            AggregateTransaction, Deadline, ModifyMultisigAccountTransaction, Transaction,
+        },
+    },
+    Result,
+};
+
+use super::{
+    CosignatoryModification, MultisigAccountGraphInfo, MultisigAccountInfo,
+    MultisigModificationType,
+};
+
+type PublicKey = [u8; PUBLIC_KEY_BYTES_SIZE];
+
+/// What is known about a cosignatory to add, from its own multisig graph.
+#[derive(Debug, Clone, Copy, Default)]
+struct CosignatoryState {
+    /// The number of multisig levels below the cosignatory, 0 for a regular account.
+    levels_below: usize,
+    /// The number of multisig accounts the cosignatory already cosigns.
+    cosigned_accounts: usize,
+}
+
+/// Builds the aggregate bonded transaction that converts an account into a multisig
+/// account, or changes the cosignatories and thresholds of a multisig account.
+///
+/// The change is validated against the current state of the account before any
+/// transaction is created, so it is not rejected by the chain once announced.
+///
+/// ```ignore
+/// let aggregate = MultisigModificationBuilder::new(account)
+///     .add_cosignatories(vec![alice, bob, carol])
+///     .min_approval(2)
+///     .min_removal(2)
+///     .build(deadline, network_type)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct MultisigModificationBuilder {
+    account: PublicAccount,
+    current_min_approval: i32,
+    current_min_removal: i32,
+    cosignatories: Vec<PublicAccount>,
+    /// The multisig accounts the account cosigns, directly or through other accounts.
+    ancestors: HashSet<PublicKey>,
+    /// The number of multisig levels above the account.
+    levels_above: usize,
+    /// The number of multisig levels below the account, 0 for a regular account.
+    levels_below: usize,
+    added_states: HashMap<PublicKey, CosignatoryState>,
+    additions: Vec<PublicAccount>,
+    removals: Vec<PublicAccount>,
+    min_approval: Option<i32>,
+    min_removal: Option<i32>,
+}
+
+impl MultisigModificationBuilder {
+    /// Starts a modification of a regular account, that is not part of any multisig.
+    pub fn new(account: PublicAccount) -> Self {
+        MultisigModificationBuilder {
+            account,
+            current_min_approval: 0,
+            current_min_removal: 0,
+            cosignatories: vec![],
+            ancestors: HashSet::new(),
+            levels_above: 0,
+            levels_below: 0,
+            added_states: HashMap::new(),
+            additions: vec![],
+            removals: vec![],
+            min_approval: None,
+            min_removal: None,
+        }
+    }
+
+    /// Starts a modification of the account of [info].
+    ///
+    /// Only the direct multisig relations of the account are known, use
+    /// [MultisigModificationBuilder::from_graph] to check loops and the depth of
+    /// the whole graph.
+    pub fn from_info(info: &MultisigAccountInfo) -> Self {
+        let mut builder = Self::new(info.account);
+        builder.current_min_approval = info.min_approval;
+        builder.current_min_removal = info.min_removal;
+        builder.cosignatories = info.cosignatories.clone();
+        builder.ancestors = info
+            .multisig_accounts
+            .iter()
+            .map(|account| account.public_key)
+            .collect();
+        builder.levels_above = if info.multisig_accounts.is_empty() {
+            0
+        } else {
+            1
+        };
+        builder.levels_below = if info.cosignatories.is_empty() { 0 } else { 1 };
+        builder
+    }
+
+    /// Starts a modification of the account the [graph] was requested for.
+    pub fn from_graph(graph: &MultisigAccountGraphInfo) -> Result<Self> {
+        let root = match graph.root() {
+            Some(root) => root,
+            None => bail!(errors_const::ERR_MULTISIG_ACCOUNT_NOT_IN_GRAPH),
+        };
+
+        let infos = graph_infos(graph);
+
+        let mut builder = Self::from_info(root);
+        builder.ancestors = ancestors_of(&root.account, &infos);
+        builder.levels_above = levels_above(&root.account, &infos, &mut HashSet::new());
+        builder.levels_below = graph.to_tree()?.depth() - 1;
+        Ok(builder)
+    }
+
+    /// Adds a cosignatory to the account.
+    pub fn add_cosignatory(mut self, cosignatory: PublicAccount) -> Self {
+        self.additions.push(cosignatory);
+        self
+    }
+
+    /// Adds cosignatories to the account.
+    pub fn add_cosignatories(mut self, cosignatories: Vec<PublicAccount>) -> Self {
+        self.additions.extend(cosignatories);
+        self
+    }
+
+    /// Adds a cosignatory to the account, using its own multisig [graph] to check the
+    /// depth of the resulting graph and the number of accounts it cosigns.
+    pub fn add_cosignatory_with_graph(mut self, graph: &MultisigAccountGraphInfo) -> Result<Self> {
+        let root = match graph.root() {
+            Some(root) => root,
+            None => bail!(errors_const::ERR_MULTISIG_ACCOUNT_NOT_IN_GRAPH),
+        };
+
+        self.added_states.insert(
+            root.account.public_key,
+            CosignatoryState {
+                levels_below: graph.to_tree()?.depth() - 1,
+                cosigned_accounts: root.multisig_accounts.len(),
+            },
+        );
+
+        Ok(self.add_cosignatory(root.account))
+    }
+
+    /// Removes a cosignatory from the account.
+    pub fn remove_cosignatory(mut self, cosignatory: PublicAccount) -> Self {
+        self.removals.push(cosignatory);
+        self
+    }
+
+    /// Sets the number of signatures needed to approve a transaction.
+    pub fn min_approval(mut self, min_approval: i32) -> Self {
+        self.min_approval = Some(min_approval);
+        self
+    }
+
+    /// Sets the number of signatures needed to remove a cosignatory.
+    pub fn min_removal(mut self, min_removal: i32) -> Self {
+        self.min_removal = Some(min_removal);
+        self
+    }
+
+    /// The cosignatories the account has once the modification is applied.
+    pub fn resulting_cosignatories(&self) -> Vec<PublicAccount> {
+        self.cosignatories
+            .iter()
+            .filter(|cosignatory| !contains(&self.removals, cosignatory))
+            .chain(self.additions.iter())
+            .copied()
+            .collect()
+    }
+
+    /// The cosignatories that have to cosign the aggregate to accept being added.
+    pub fn required_cosignatories(&self) -> Vec<PublicAccount> {
+        self.additions.clone()
+    }
+
+    fn validate_cosignatories(&self) -> Result<usize> {
+        ensure!(
+            self.removals.len() <= 1,
+            errors_const::ERR_MULTISIG_MULTIPLE_REMOVALS
+        );
+
+        for removal in self.removals.iter() {
+            ensure!(
+                contains(&self.cosignatories, removal),
+                errors_const::ERR_MULTISIG_COSIGNATORY_NOT_PRESENT
+            );
+        }
+
+        let mut seen = HashSet::new();
+        for addition in self.additions.iter() {
+            ensure!(
+                addition.public_key != self.account.public_key,
+                errors_const::ERR_MULTISIG_SELF_COSIGNATORY
+            );
+
+            ensure!(
+                !contains(&self.cosignatories, addition) && seen.insert(addition.public_key),
+                errors_const::ERR_MULTISIG_COSIGNATORY_PRESENT
+            );
+
+            ensure!(
+                !self.ancestors.contains(&addition.public_key),
+                errors_const::ERR_MULTISIG_LOOP
+            );
+
+            let state = self
+                .added_states
+                .get(&addition.public_key)
+                .copied()
+                .unwrap_or_default();
+
+            ensure!(
+                state.cosigned_accounts < MULTISIG_MAX_COSIGNED_ACCOUNTS,
+                errors_const::ERR_MULTISIG_TOO_MANY_COSIGNED_ACCOUNTS
+            );
+
+            ensure!(
+                self.levels_above + 1 + state.levels_below <= MULTISIG_MAX_DEPTH,
+                errors_const::ERR_MULTISIG_MAX_DEPTH
+            );
+        }
+
+        let count = self.cosignatories.len() + self.additions.len() - self.removals.len();
+
+        ensure!(
+            count <= MULTISIG_MAX_COSIGNATORIES,
+            errors_const::ERR_MULTISIG_TOO_MANY_COSIGNATORIES
+        );
+
+        if count > 0 {
+            ensure!(
+                self.levels_above + 1 + self.levels_below.saturating_sub(1) <= MULTISIG_MAX_DEPTH,
+                errors_const::ERR_MULTISIG_MAX_DEPTH
+            );
+        }
+
+        Ok(count)
+    }
+
+    /// Returns the delta from the [current] threshold to the [desired] one.
+    ///
+    /// Removing the last cosignatory turns the account back into a regular account,
+    /// whose thresholds are zero.
+    fn threshold_delta(
+        current: i32,
+        desired: Option<i32>,
+        count: usize,
+        error: &'static str,
+    ) -> Result<i8> {
+        let target = match desired {
+            Some(target) => target,
+            None if count == 0 => 0,
+            None => current,
+        };
+
+        if count == 0 {
+            ensure!(target == 0, error);
+        } else {
+            ensure!(target >= 1 && target as usize <= count, error);
+        }
+
+        match i8::try_from(target - current) {
+            Ok(delta) => Ok(delta),
+            Err(_) => bail!(errors_const::ERR_MULTISIG_DELTA_OUT_OF_RANGE),
+        }
+    }
+
+    /// Validates the modification and creates the modify multisig account transaction.
+    pub fn build_transaction(
+        &self,
+        deadline: Deadline,
+        network_type: NetworkType,
+    ) -> Result<ModifyMultisigAccountTransaction> {
+        let count = self.validate_cosignatories()?;
+
+        let min_approval_delta = Self::threshold_delta(
+            self.current_min_approval,
+            self.min_approval,
+            count,
+            errors_const::ERR_MULTISIG_INVALID_MIN_APPROVAL,
+        )?;
+
+        let min_removal_delta = Self::threshold_delta(
+            self.current_min_removal,
+            self.min_removal,
+            count,
+            errors_const::ERR_MULTISIG_INVALID_MIN_REMOVAL,
+        )?;
+
+        let modifications: Vec<CosignatoryModification> = self
+            .additions
+            .iter()
+            .map(|account| CosignatoryModification::new(MultisigModificationType::Add, *account))
+            .chain(self.removals.iter().map(|account| {
+                CosignatoryModification::new(MultisigModificationType::Remove, *account)
+            }))
+            .collect();
+
+        ensure!(
+            !modifications.is_empty() || min_approval_delta != 0 || min_removal_delta != 0,
+            errors_const::ERR_MULTISIG_NO_CHANGE
+        );
+
+        ModifyMultisigAccountTransaction::new(
+            deadline,
+            min_approval_delta,
+            min_removal_delta,
+            modifications,
+            network_type,
+        )
+    }
+
+    /// Validates the modification and creates the aggregate bonded transaction that
+    /// applies it, ready to be signed by the account.
+    ///
+    /// The aggregate has to be announced after a hash lock, and cosigned by the
+    /// [required_cosignatories](MultisigModificationBuilder::required_cosignatories).
+    pub fn build(
+        &self,
+        deadline: Deadline,
+        network_type: NetworkType,
+    ) -> Result<AggregateTransaction> {
+        let mut modify = self.build_transaction(deadline, network_type)?;
+
+        modify.set_aggregate(self.account);
+
+        AggregateTransaction::new_bonded(deadline, vec![Box::new(modify)], network_type)
+    }
+
+    /// Creates the aggregate bonded transaction on the network described by [properties],
+    /// checking the deadline against its maximum deadline.
+    pub fn build_with_properties(
+        &self,
+        properties: &NetworkProperties,
+        deadline: Deadline,
+    ) -> Result<AggregateTransaction> {
+        let deadline = properties.validate_deadline(deadline)?;
+
+        self.build(deadline, properties.network_type)
+    }
+}
+
+fn contains(accounts: &[PublicAccount], account: &PublicAccount) -> bool {
+    accounts
+        .iter()
+        .any(|present| present.public_key == account.public_key)
+}
+
+fn graph_infos(graph: &MultisigAccountGraphInfo) -> HashMap<PublicKey, &MultisigAccountInfo> {
+    graph
+        .multisig_accounts
+        .values()
+        .flatten()
+        .map(|info| (info.account.public_key, info))
+        .collect()
+}
+
+/// Every multisig account [account] cosigns, directly or through other accounts.
+fn ancestors_of(
+    account: &PublicAccount,
+    infos: &HashMap<PublicKey, &MultisigAccountInfo>,
+) -> HashSet<PublicKey> {
+    let mut ancestors = HashSet::new();
+    let mut pending = vec![account.public_key];
+
+    while let Some(key) = pending.pop() {
+        if let Some(info) = infos.get(&key) {
+            for parent in info.multisig_accounts.iter() {
+                if ancestors.insert(parent.public_key) {
+                    pending.push(parent.public_key);
+                }
+            }
+        }
+    }
+
+    ancestors
+}
+
+/// The longest chain of multisig accounts above [account].
+fn levels_above(
+    account: &PublicAccount,
+    infos: &HashMap<PublicKey, &MultisigAccountInfo>,
+    path: &mut HashSet<PublicKey>,
+) -> usize {
+    let info = match infos.get(&account.public_key) {
+        Some(info) if path.insert(account.public_key) => info,
+        _ => return 0,
+    };
+
+    let levels = info
+        .multisig_accounts
+        .iter()
+        .map(|parent| 1 + levels_above(parent, infos, path))
+        .max()
+        .unwrap_or_default();
+
+    path.remove(&account.public_key);
+
+    levels
+}
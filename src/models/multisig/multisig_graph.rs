@@ -0,0 +1,289 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use crate::{
+    models::{account::PublicAccount, consts::PUBLIC_KEY_BYTES_SIZE, errors_const},
+    Result,
+};
+
+use super::{MultisigAccountGraphInfo, MultisigAccountInfo};
+
+/// The threshold of a multisig account that has to be met.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum MultisigThresholdType {
+    /// The number of signatures needed to approve a transaction.
+    MinApproval,
+    /// The number of signatures needed to remove a cosignatory.
+    MinRemoval,
+}
+
+/// A node of the multisig account tree.
+///
+/// A node without cosignatories is a regular account that signs for itself, any other node
+/// is a multisig account whose cosignatories are the child nodes.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultisigAccountTree {
+    pub account: PublicAccount,
+    pub min_approval: i32,
+    pub min_removal: i32,
+    pub cosignatories: Vec<MultisigAccountTree>,
+}
+
+impl MultisigAccountTree {
+    /// Returns true if the account of this node is a multisig account.
+    pub fn is_multisig(&self) -> bool {
+        !self.cosignatories.is_empty()
+    }
+
+    /// The number of levels of this tree, a single account has a depth of 1.
+    pub fn depth(&self) -> usize {
+        1 + self
+            .cosignatories
+            .iter()
+            .map(|node| node.depth())
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Visits every node of the tree depth-first, starting at this node.
+    ///
+    /// The callback receives the node and its level, where this node is level 0.
+    pub fn walk<F>(&self, mut f: F)
+    where
+        F: FnMut(&MultisigAccountTree, usize),
+    {
+        self.walk_level(&mut f, 0)
+    }
+
+    fn walk_level<F>(&self, f: &mut F, level: usize)
+    where
+        F: FnMut(&MultisigAccountTree, usize),
+    {
+        f(self, level);
+        for node in self.cosignatories.iter() {
+            node.walk_level(f, level + 1);
+        }
+    }
+
+    /// Finds the node of the given account in the tree.
+    pub fn find(&self, account: &PublicAccount) -> Option<&MultisigAccountTree> {
+        if self.account.public_key == account.public_key {
+            return Some(self);
+        }
+
+        self.cosignatories
+            .iter()
+            .find_map(|node| node.find(account))
+    }
+
+    /// All the regular accounts that can sign for this node, without duplicates.
+    pub fn signers(&self) -> Vec<PublicAccount> {
+        let mut seen = HashSet::new();
+        let mut signers = vec![];
+        self.walk(|node, _| {
+            if !node.is_multisig() && seen.insert(node.account.public_key) {
+                signers.push(node.account)
+            }
+        });
+        signers
+    }
+
+    /// Resolves which signatures are collected and which are missing to meet the
+    /// [threshold] of this account with the [available] cosigner signatures.
+    ///
+    /// The [threshold] is applied to this account only, nested multisig accounts always
+    /// have to meet their `min_approval`.
+    pub fn signature_status(
+        &self,
+        threshold: MultisigThresholdType,
+        available: &[PublicAccount],
+    ) -> MultisigSignatureStatus {
+        let available: HashSet<_> = available.iter().map(|a| a.public_key).collect();
+        self.status_with(threshold, &available)
+    }
+
+    /// Returns true if the [threshold] of this account can be met with the
+    /// [available] cosigner signatures.
+    pub fn can_sign(&self, threshold: MultisigThresholdType, available: &[PublicAccount]) -> bool {
+        self.signature_status(threshold, available).is_complete
+    }
+
+    fn required(&self, threshold: MultisigThresholdType) -> usize {
+        let min = match threshold {
+            MultisigThresholdType::MinApproval => self.min_approval,
+            MultisigThresholdType::MinRemoval => self.min_removal,
+        };
+
+        (min.max(1) as usize).min(self.cosignatories.len())
+    }
+
+    fn status_with(
+        &self,
+        threshold: MultisigThresholdType,
+        available: &HashSet<[u8; PUBLIC_KEY_BYTES_SIZE]>,
+    ) -> MultisigSignatureStatus {
+        if !self.is_multisig() {
+            let signed = available.contains(&self.account.public_key);
+            return MultisigSignatureStatus {
+                account: self.account,
+                required: 1,
+                collected: signed as usize,
+                is_complete: signed,
+                missing: if signed { vec![] } else { vec![self.account] },
+                cosignatories: vec![],
+            };
+        }
+
+        let cosignatories: Vec<MultisigSignatureStatus> = self
+            .cosignatories
+            .iter()
+            .map(|node| node.status_with(MultisigThresholdType::MinApproval, available))
+            .collect();
+
+        let required = self.required(threshold);
+        let collected = cosignatories.iter().filter(|s| s.is_complete).count();
+        let is_complete = collected >= required;
+
+        let missing = if is_complete {
+            vec![]
+        } else {
+            let mut seen = HashSet::new();
+            cosignatories
+                .iter()
+                .flat_map(|s| s.missing.iter())
+                .filter(|a| seen.insert(a.public_key))
+                .cloned()
+                .collect()
+        };
+
+        MultisigSignatureStatus {
+            account: self.account,
+            required,
+            collected,
+            is_complete,
+            missing,
+            cosignatories,
+        }
+    }
+}
+
+impl fmt::Display for MultisigAccountTree {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).unwrap_or_default()
+        )
+    }
+}
+
+/// The signature progress of an account of a [`MultisigAccountTree`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultisigSignatureStatus {
+    pub account: PublicAccount,
+    /// The number of cosignatories that have to sign, 1 for a regular account.
+    pub required: usize,
+    /// The number of cosignatories that have signed.
+    pub collected: usize,
+    /// Returns true if the threshold of the account is met.
+    pub is_complete: bool,
+    /// The regular accounts that can still sign to help meet the threshold.
+    pub missing: Vec<PublicAccount>,
+    /// The progress of every cosignatory of a multisig account.
+    pub cosignatories: Vec<MultisigSignatureStatus>,
+}
+
+impl MultisigSignatureStatus {
+    /// The number of cosignatories that still have to sign.
+    pub fn remaining(&self) -> usize {
+        self.required.saturating_sub(self.collected)
+    }
+}
+
+impl fmt::Display for MultisigSignatureStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).unwrap_or_default()
+        )
+    }
+}
+
+impl MultisigAccountGraphInfo {
+    /// The multisig account the graph was requested for, at level 0.
+    pub fn root(&self) -> Option<&MultisigAccountInfo> {
+        self.multisig_accounts
+            .get(&0)
+            .and_then(|level| level.first())
+    }
+
+    /// Builds the cosignatory tree of the account the graph was requested for.
+    pub fn to_tree(&self) -> Result<MultisigAccountTree> {
+        match self.root() {
+            Some(root) => self.tree_of(&root.account),
+            None => bail!(errors_const::ERR_MULTISIG_ACCOUNT_NOT_IN_GRAPH),
+        }
+    }
+
+    /// Builds the cosignatory tree of the given multisig [account] of the graph.
+    pub fn tree_of(&self, account: &PublicAccount) -> Result<MultisigAccountTree> {
+        let infos: HashMap<[u8; PUBLIC_KEY_BYTES_SIZE], &MultisigAccountInfo> = self
+            .multisig_accounts
+            .values()
+            .flatten()
+            .map(|info| (info.account.public_key, info))
+            .collect();
+
+        ensure!(
+            infos.contains_key(&account.public_key),
+            errors_const::ERR_MULTISIG_ACCOUNT_NOT_IN_GRAPH
+        );
+
+        Ok(build_tree(account, &infos, &mut HashSet::new()))
+    }
+}
+
+fn build_tree(
+    account: &PublicAccount,
+    infos: &HashMap<[u8; PUBLIC_KEY_BYTES_SIZE], &MultisigAccountInfo>,
+    path: &mut HashSet<[u8; PUBLIC_KEY_BYTES_SIZE]>,
+) -> MultisigAccountTree {
+    let info = match infos.get(&account.public_key) {
+        // An account already on the path is treated as a regular account to stop cycles.
+        Some(info) if !info.cosignatories.is_empty() && path.insert(account.public_key) => info,
+        _ => {
+            return MultisigAccountTree {
+                account: *account,
+                min_approval: 0,
+                min_removal: 0,
+                cosignatories: vec![],
+            }
+        }
+    };
+
+    let cosignatories = info
+        .cosignatories
+        .iter()
+        .map(|cosignatory| build_tree(cosignatory, infos, path))
+        .collect();
+
+    path.remove(&account.public_key);
+
+    MultisigAccountTree {
+        account: *account,
+        min_approval: info.min_approval,
+        min_removal: info.min_removal,
+        cosignatories,
+    }
+}
@@ -16,7 +16,7 @@ use crate::models::{
 /// The type of the modification:
 /// * 0 - Add cosignatory.
 /// * 1 - Remove cosignatory.
-#[derive(Debug, Clone, Serialize, Deserialize, IntoPrimitive)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, IntoPrimitive)]
 #[repr(u8)]
 pub enum MultisigModificationType {
     Add,
@@ -7,7 +7,9 @@
 pub(crate) use self::internal::*;
 pub use self::namespace_id::*;
 pub use self::namespace_model::*;
+pub use self::namespace_tree::*;
 
 mod internal;
 mod namespace_id;
 mod namespace_model;
+mod namespace_tree;
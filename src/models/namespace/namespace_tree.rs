@@ -0,0 +1,264 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    models::{
+        account::PublicAccount,
+        errors_const,
+        network::NetworkType,
+        transaction::{
+            AggregateTransaction, Deadline, Height, RegisterNamespaceTransaction, Transaction,
+        },
+        AssetId, Uint64,
+    },
+    Result,
+};
+
+use super::{NamespaceAlias, NamespaceId, NamespaceInfo};
+
+/// A namespace together with all its sub-namespaces.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamespaceTree {
+    pub namespace_id: NamespaceId,
+    /// The full name of the namespace, empty when it is not known.
+    pub name: String,
+    pub depth: u8,
+    pub active: bool,
+    pub alias: NamespaceAlias,
+    pub owner: PublicAccount,
+    pub start_height: Height,
+    /// The height at which the namespace, and all its sub-namespaces, expire.
+    pub end_height: Height,
+    pub children: Vec<NamespaceTree>,
+}
+
+impl NamespaceTree {
+    /// Builds the tree of [root] out of the given [namespaces], which must contain its
+    /// sub-namespaces. Namespaces that are not below [root] are ignored.
+    ///
+    /// The [names] of the levels are keyed by namespace id.
+    pub fn build(
+        root: &NamespaceInfo,
+        namespaces: &[NamespaceInfo],
+        names: &HashMap<u64, String>,
+    ) -> Self {
+        let children = namespaces
+            .iter()
+            .filter(|info| {
+                info.levels.len() == root.levels.len() + 1 && info.levels.starts_with(&root.levels)
+            })
+            .map(|info| Self::build(info, namespaces, names))
+            .collect();
+
+        NamespaceTree {
+            namespace_id: root.namespace_id,
+            name: names
+                .get(&root.namespace_id.to_u64())
+                .cloned()
+                .unwrap_or_default(),
+            depth: root.depth,
+            active: root.active,
+            alias: root.alias.clone(),
+            owner: root.owner,
+            start_height: root.start_height,
+            end_height: root.end_height,
+            children,
+        }
+    }
+
+    /// Visits every namespace of the tree depth-first, starting at this one.
+    pub fn walk<F>(&self, f: &mut F)
+    where
+        F: FnMut(&NamespaceTree),
+    {
+        f(self);
+        for child in self.children.iter() {
+            child.walk(f);
+        }
+    }
+
+    /// Finds a namespace of the tree by id.
+    pub fn find(&self, namespace_id: NamespaceId) -> Option<&NamespaceTree> {
+        if self.namespace_id == namespace_id {
+            return Some(self);
+        }
+
+        self.children
+            .iter()
+            .find_map(|child| child.find(namespace_id))
+    }
+
+    /// Returns true if the namespace is expired at the given height.
+    pub fn is_expired_at(&self, height: Height) -> bool {
+        *height >= *self.end_height
+    }
+}
+
+impl fmt::Display for NamespaceTree {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+/// The registration state of one level of a dotted namespace name.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamespaceLevel {
+    /// The name of this level.
+    pub name: String,
+    /// The dotted name up to and including this level.
+    pub full_name: String,
+    pub namespace_id: NamespaceId,
+    /// The registered namespace, `None` when this level was never registered. An expired
+    /// namespace is kept until it is registered again.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub info: Option<NamespaceInfo>,
+    /// Whether the namespace is active at the height the status was read at.
+    pub active: bool,
+}
+
+impl NamespaceLevel {
+    /// Creates the level of a namespace read at the chain [height]. The namespace is
+    /// active when the node reports it so and it is not expired at [height].
+    pub fn new(
+        name: String,
+        full_name: String,
+        namespace_id: NamespaceId,
+        info: Option<NamespaceInfo>,
+        height: Height,
+    ) -> Self {
+        let active = info
+            .as_ref()
+            .map(|info| info.active && *height < *info.end_height)
+            .unwrap_or(false);
+
+        NamespaceLevel {
+            name,
+            full_name,
+            namespace_id,
+            info,
+            active,
+        }
+    }
+
+    /// Returns true if the level is registered and active. An expired level counts as
+    /// missing, as it has to be registered again.
+    pub fn exists(&self) -> bool {
+        self.info.is_some() && self.active
+    }
+
+    /// Returns true if the level was registered but is expired.
+    pub fn is_expired(&self) -> bool {
+        self.info.is_some() && !self.active
+    }
+
+    pub fn owner(&self) -> Option<PublicAccount> {
+        self.info.as_ref().map(|info| info.owner)
+    }
+
+    pub fn end_height(&self) -> Option<Height> {
+        self.info.as_ref().map(|info| info.end_height)
+    }
+}
+
+/// Which levels of a dotted namespace name already exist and who owns them.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamespacePathStatus {
+    pub full_name: String,
+    /// The chain height the status was read at.
+    pub height: Height,
+    pub levels: Vec<NamespaceLevel>,
+}
+
+impl NamespacePathStatus {
+    /// Returns true if every level of the name exists.
+    pub fn is_registered(&self) -> bool {
+        self.levels.iter().all(|level| level.exists())
+    }
+
+    /// The levels that do not exist yet or are expired.
+    pub fn missing_levels(&self) -> Vec<&NamespaceLevel> {
+        self.levels.iter().filter(|level| !level.exists()).collect()
+    }
+
+    /// Builds an aggregate that registers the missing levels of the name for [signer].
+    ///
+    /// The [duration] is only used when the root namespace is missing or expired, in
+    /// which case the root is registered again. Renewing its own root brings back the
+    /// sub-namespaces of [signer], while a root expired from another account is
+    /// registered with every level below it. Fails if the active levels are owned by
+    /// another account.
+    pub fn register_missing(
+        &self,
+        deadline: Deadline,
+        duration: Uint64,
+        signer: PublicAccount,
+        network_type: NetworkType,
+    ) -> Result<AggregateTransaction> {
+        ensure!(
+            !self.is_registered(),
+            errors_const::ERR_NAMESPACE_PATH_REGISTERED
+        );
+
+        let is_signer = |level: &NamespaceLevel| {
+            level.owner().map(|owner| owner.public_key) == Some(signer.public_key)
+        };
+
+        for level in self.levels.iter().filter(|level| level.exists()) {
+            ensure!(is_signer(level), errors_const::ERR_NAMESPACE_OWNED_BY_OTHER);
+        }
+
+        // The sub-namespaces of a root are only kept while its owner does not change.
+        let keeps_sub_namespaces = match self.levels.first() {
+            Some(root) => is_signer(root),
+            None => false,
+        };
+
+        let transactions = RegisterNamespaceTransaction::create_path(
+            deadline,
+            &self.full_name,
+            duration,
+            network_type,
+        )?;
+
+        let inner_transactions = transactions
+            .into_iter()
+            .zip(self.levels.iter())
+            .enumerate()
+            .filter(|(depth, (_, level))| {
+                if *depth == 0 {
+                    !level.exists()
+                } else {
+                    !(keeps_sub_namespaces && level.info.is_some())
+                }
+            })
+            .map(|(_, (mut tx, _))| {
+                tx.set_aggregate(signer);
+                Box::new(tx) as Box<dyn Transaction>
+            })
+            .collect();
+
+        AggregateTransaction::new_complete(deadline, inner_transactions, network_type)
+    }
+}
+
+impl fmt::Display for NamespacePathStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
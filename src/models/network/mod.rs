@@ -5,7 +5,9 @@
  */
 
 pub(crate) use self::network_internal::*;
+pub use self::network_properties::*;
 pub use self::network_type_model::*;
 
 mod network_internal;
+mod network_properties;
 mod network_type_model;
@@ -0,0 +1,291 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use ::std::{collections::HashMap, time::Duration};
+
+use crate::{
+    errors_const::{
+        ERR_DEADLINE_EPOCH_MISMATCH, ERR_INVALID_NETWORK_CONFIG, ERR_MESSAGE_TOO_LARGE,
+        ERR_TRANSACTION_NETWORK_MISMATCH,
+    },
+    models::{
+        message::Message,
+        mosaic::{Mosaic, MosaicAmount, MosaicId, PRX_XPX_U64},
+        transaction::{
+            AggregateTransaction, Deadline, HashValue, SystemTimeSource, TimeSource, Timestamp,
+            Transaction, TransferTransaction, MAX_DEADLINE_WINDOW,
+            TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS,
+        },
+        Uint64,
+    },
+    Result,
+};
+
+use super::NetworkType;
+
+/// The default divisibility of the network currency.
+pub const CURRENCY_DIVISIBILITY: u8 = 6;
+/// The default maximum size of a transfer message, in bytes including its type.
+pub const MAX_MESSAGE_SIZE: usize = 1024;
+
+const CONFIG_CHAIN_SECTION: &str = "chain";
+const CONFIG_TRANSFER_SECTION: &str = "plugin:catapult.plugins.transfer";
+
+/// The configuration of a network in effect from a height, as served by a node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkConfig {
+    /// The height the configuration applies from.
+    pub height: Uint64,
+    /// The configuration, in the ini format of the node.
+    pub network_config: String,
+    pub supported_entity_versions: String,
+}
+
+impl core::fmt::Display for NetworkConfig {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+/// The parameters of a network, loaded from a node when a client connects.
+///
+/// The defaults are the ones of the ProximaX public networks.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkProperties {
+    pub network_type: NetworkType,
+    pub generation_hash: HashValue,
+    /// The time, in milliseconds since the Unix epoch, the network time counts from.
+    pub epoch: i64,
+    /// The timestamp of the nemesis block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nemesis_timestamp: Option<Timestamp>,
+    pub currency_mosaic_id: MosaicId,
+    pub harvesting_mosaic_id: MosaicId,
+    pub currency_divisibility: u8,
+    /// The maximum time between the announcement of a transaction and its deadline.
+    pub max_deadline: Duration,
+    /// The maximum size of a transfer message, in bytes including its type.
+    pub max_message_size: usize,
+}
+
+impl Default for NetworkProperties {
+    fn default() -> Self {
+        NetworkProperties {
+            network_type: Default::default(),
+            generation_hash: HashValue::zero(),
+            epoch: TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS,
+            nemesis_timestamp: None,
+            currency_mosaic_id: MosaicId::from(PRX_XPX_U64),
+            harvesting_mosaic_id: MosaicId::from(PRX_XPX_U64),
+            currency_divisibility: CURRENCY_DIVISIBILITY,
+            max_deadline: MAX_DEADLINE_WINDOW,
+            max_message_size: MAX_MESSAGE_SIZE,
+        }
+    }
+}
+
+/// Parses the sections of a network configuration, in the ini format of the node.
+fn parse_config(config: &str) -> HashMap<String, HashMap<String, String>> {
+    let mut sections: HashMap<String, HashMap<String, String>> = HashMap::new();
+    let mut section = String::new();
+
+    for line in config.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with('[') && line.ends_with(']') {
+            section = line[1..line.len() - 1].trim().to_string();
+        } else if let Some(index) = line.find('=') {
+            sections.entry(section.clone()).or_default().insert(
+                line[..index].trim().to_string(),
+                line[index + 1..].trim().to_string(),
+            );
+        }
+    }
+
+    sections
+}
+
+/// Parses a number of the configuration, which may use `'` as a digit separator.
+fn parse_config_u64(value: &str) -> Result<u64> {
+    let value = value.replace('\'', "");
+
+    let number = match value.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => value.parse::<u64>(),
+    };
+
+    number.map_err(|_| format_err!("{} {}", ERR_INVALID_NETWORK_CONFIG, value))
+}
+
+/// Parses a duration of the configuration, such as `24h`, `15s` or `500ms`.
+fn parse_config_duration(value: &str) -> Result<Duration> {
+    let value = value.replace('\'', "");
+    let index = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+
+    let amount = parse_config_u64(&value[..index])?;
+
+    let duration = match &value[index..] {
+        "ms" => Duration::from_millis(amount),
+        "s" => Duration::from_secs(amount),
+        "m" => Duration::from_secs(amount * 60),
+        "h" => Duration::from_secs(amount * 60 * 60),
+        "d" => Duration::from_secs(amount * 24 * 60 * 60),
+        _ => bail!("{} {}", ERR_INVALID_NETWORK_CONFIG, value),
+    };
+
+    Ok(duration)
+}
+
+impl NetworkProperties {
+    /// Creates the properties of a network from its [config], the ini text served by a
+    /// node. Values missing from [config] keep their default.
+    pub fn from_config(
+        network_type: NetworkType,
+        generation_hash: HashValue,
+        config: &str,
+    ) -> Result<Self> {
+        let sections = parse_config(config);
+
+        let mut properties = NetworkProperties {
+            network_type,
+            generation_hash,
+            ..Default::default()
+        };
+
+        if let Some(chain) = sections.get(CONFIG_CHAIN_SECTION) {
+            if let Some(value) = chain.get("currencyMosaicId") {
+                properties.currency_mosaic_id = MosaicId::from(parse_config_u64(value)?);
+            }
+            if let Some(value) = chain.get("harvestingMosaicId") {
+                properties.harvesting_mosaic_id = MosaicId::from(parse_config_u64(value)?);
+            }
+            if let Some(value) = chain.get("maxTransactionLifetime") {
+                properties.max_deadline = parse_config_duration(value)?;
+            }
+        }
+
+        if let Some(value) = sections
+            .get("network")
+            .and_then(|network| network.get("epochAdjustment"))
+        {
+            properties.epoch = parse_config_duration(value)?.as_millis() as i64;
+        }
+
+        if let Some(value) = sections
+            .get(CONFIG_TRANSFER_SECTION)
+            .and_then(|transfer| transfer.get("maxMessageSize"))
+        {
+            properties.max_message_size = parse_config_u64(value)? as usize;
+        }
+
+        Ok(properties)
+    }
+
+    /// Creates a `Deadline` [duration] from now, on the network epoch and within the
+    /// maximum deadline.
+    pub fn deadline(&self, duration: chrono::Duration) -> Result<Deadline> {
+        self.deadline_with_time_source(&SystemTimeSource, duration)
+    }
+
+    /// Creates a `Deadline` [duration] from the current time of [time_source], on the
+    /// network epoch and within the maximum deadline.
+    pub fn deadline_with_time_source<T: TimeSource + ?Sized>(
+        &self,
+        time_source: &T,
+        duration: chrono::Duration,
+    ) -> Result<Deadline> {
+        let deadline =
+            Deadline::from_duration_with_time_source(time_source, duration)?.with_epoch(self.epoch);
+
+        deadline.validate_with_time_source(time_source, self.max_deadline)?;
+
+        Ok(deadline)
+    }
+
+    /// Checks [deadline] is in the future and within the maximum deadline, and returns it
+    /// on the network epoch.
+    pub fn validate_deadline(&self, deadline: Deadline) -> Result<Deadline> {
+        deadline.validate(self.max_deadline)?;
+
+        Ok(deadline.with_epoch(self.epoch))
+    }
+
+    /// Checks [message] fits in a transfer.
+    pub fn validate_message(&self, message: &dyn Message) -> Result<()> {
+        // The size counted by the network includes the message type, hence the strict bound.
+        ensure!(
+            message.payload_to_bytes().len() < self.max_message_size,
+            ERR_MESSAGE_TOO_LARGE
+        );
+
+        Ok(())
+    }
+
+    /// Checks [transaction] was built for this network: its network type, a deadline on the
+    /// network epoch within the maximum deadline, and the size of the transfer messages,
+    /// including the ones of the inner transactions of an aggregate.
+    pub fn validate_transaction(&self, transaction: &dyn Transaction) -> Result<()> {
+        let abs_transaction = transaction.abs_transaction();
+
+        if let Some(deadline) = abs_transaction.deadline {
+            ensure!(deadline.epoch() == self.epoch, ERR_DEADLINE_EPOCH_MISMATCH);
+
+            deadline.validate(self.max_deadline)?;
+        }
+
+        self.validate_entity(transaction)
+    }
+
+    fn validate_entity(&self, transaction: &dyn Transaction) -> Result<()> {
+        ensure!(
+            transaction.abs_transaction().network_type == self.network_type,
+            ERR_TRANSACTION_NETWORK_MISMATCH
+        );
+
+        if let Some(transfer) = transaction.try_downcast_ref::<TransferTransaction>() {
+            self.validate_message(transfer.message.as_ref())?;
+        } else if let Some(aggregate) = transaction.try_downcast_ref::<AggregateTransaction>() {
+            for inner in aggregate.inner_transactions.iter() {
+                self.validate_entity(inner.as_ref())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Creates a [Mosaic] of the network currency, with an [amount] in smallest units.
+    pub fn currency(&self, amount: u64) -> Mosaic {
+        Mosaic::new(self.currency_mosaic_id, amount)
+    }
+
+    /// Creates a [Mosaic] of the network currency, with a relative [amount] like `"1.5"`.
+    pub fn currency_relative(&self, amount: &str) -> Result<Mosaic> {
+        let amount = MosaicAmount::parse(amount, self.currency_divisibility)?;
+
+        Ok(self.currency(*amount.amount()))
+    }
+}
+
+impl core::fmt::Display for NetworkProperties {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
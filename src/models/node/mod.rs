@@ -4,6 +4,8 @@
  * license that can be found in the LICENSE file.
  */
 
+pub use self::network_time::*;
 pub use self::node_model::*;
 
+mod network_time;
 mod node_model;
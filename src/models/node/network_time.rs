@@ -0,0 +1,196 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use ::std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{
+    errors_const::{ERR_CLOCK_SKEW_TOO_LARGE, ERR_INVALID_NODE_TIME},
+    models::transaction::TimeSource,
+    Result,
+};
+
+use super::NodeTime;
+
+/// The default number of node time requests an offset is estimated from.
+pub const CLOCK_OFFSET_SAMPLES: usize = 3;
+/// The default skew above which the local clock is reported as drifting.
+pub const CLOCK_SKEW_WARNING: Duration = Duration::from_secs(5);
+/// The default skew above which the local clock, or the node clock, is not trusted.
+pub const CLOCK_SKEW_LIMIT: Duration = Duration::from_secs(60);
+
+/// How far the local clock is from the node clock.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ClockSkewLevel {
+    Normal,
+    /// The skew exceeds the warning threshold, deadlines should use the network time.
+    Warning,
+    /// The skew exceeds the limit.
+    Excessive,
+}
+
+impl core::fmt::Display for ClockSkewLevel {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The skews at which a [ClockOffset] is reported as a warning or an error.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ClockSkewThresholds {
+    pub warning: Duration,
+    pub limit: Duration,
+}
+
+impl Default for ClockSkewThresholds {
+    fn default() -> Self {
+        ClockSkewThresholds {
+            warning: CLOCK_SKEW_WARNING,
+            limit: CLOCK_SKEW_LIMIT,
+        }
+    }
+}
+
+/// The offset of a node clock from the local clock, estimated NTP style from one node
+/// time request.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClockOffset {
+    /// The node time minus the local time, in milliseconds.
+    pub offset_ms: i64,
+    /// The time the request spent on the network, in milliseconds.
+    pub round_trip_ms: u64,
+}
+
+fn unix_milliseconds(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_millis() as i64,
+        Err(err) => -(err.duration().as_millis() as i64),
+    }
+}
+
+impl ClockOffset {
+    /// Estimates the offset from the [node_time] of a request sent at [request_sent] and
+    /// answered at [response_received], both read on the local clock.
+    ///
+    /// The node reports its time in milliseconds since the network [epoch].
+    pub fn estimate(
+        request_sent: SystemTime,
+        node_time: &NodeTime,
+        response_received: SystemTime,
+        epoch: i64,
+    ) -> Result<Self> {
+        let (node_receive, node_send) =
+            match (node_time.receive_timestamp, node_time.send_timestamp) {
+                (Some(receive), Some(send)) if receive.as_u64() > 0 && send.as_u64() > 0 => (
+                    receive.as_u64() as i64 + epoch,
+                    send.as_u64() as i64 + epoch,
+                ),
+                _ => bail!(ERR_INVALID_NODE_TIME),
+            };
+
+        let local_send = unix_milliseconds(request_sent);
+        let local_receive = unix_milliseconds(response_received);
+
+        let offset_ms = ((node_receive - local_send) + (node_send - local_receive)) / 2;
+        let round_trip_ms = ((local_receive - local_send) - (node_send - node_receive)).max(0);
+
+        Ok(ClockOffset {
+            offset_ms,
+            round_trip_ms: round_trip_ms as u64,
+        })
+    }
+
+    /// The absolute difference between the clocks.
+    pub fn skew(&self) -> Duration {
+        Duration::from_millis(self.offset_ms.unsigned_abs())
+    }
+
+    pub fn level(&self, thresholds: ClockSkewThresholds) -> ClockSkewLevel {
+        let skew = self.skew();
+        if skew > thresholds.limit {
+            ClockSkewLevel::Excessive
+        } else if skew > thresholds.warning {
+            ClockSkewLevel::Warning
+        } else {
+            ClockSkewLevel::Normal
+        }
+    }
+
+    /// Returns the level of the skew, or an error when it exceeds the limit.
+    pub fn check(&self, thresholds: ClockSkewThresholds) -> Result<ClockSkewLevel> {
+        let level = self.level(thresholds);
+        ensure!(level != ClockSkewLevel::Excessive, ERR_CLOCK_SKEW_TOO_LARGE);
+        Ok(level)
+    }
+
+    /// Converts a [time] of the local clock to the node clock.
+    pub fn apply(&self, time: SystemTime) -> SystemTime {
+        let offset = Duration::from_millis(self.offset_ms.unsigned_abs());
+        if self.offset_ms >= 0 {
+            time + offset
+        } else {
+            time - offset
+        }
+    }
+}
+
+impl core::fmt::Display for ClockOffset {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
+
+/// A [TimeSource] following the clock of a node, so that deadlines are accepted by the
+/// network even when the local clock drifts.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkTimeSource {
+    offset: ClockOffset,
+    skew_level: ClockSkewLevel,
+}
+
+impl NetworkTimeSource {
+    /// Creates a time source from [offset], or an error when its skew exceeds the limit
+    /// of [thresholds].
+    pub fn new(offset: ClockOffset, thresholds: ClockSkewThresholds) -> Result<Self> {
+        let skew_level = offset.check(thresholds)?;
+
+        Ok(NetworkTimeSource { offset, skew_level })
+    }
+
+    pub fn offset(&self) -> ClockOffset {
+        self.offset
+    }
+
+    pub fn skew_level(&self) -> ClockSkewLevel {
+        self.skew_level
+    }
+
+    /// Whether the skew exceeds the warning threshold.
+    pub fn is_skewed(&self) -> bool {
+        self.skew_level != ClockSkewLevel::Normal
+    }
+}
+
+impl TimeSource for NetworkTimeSource {
+    fn now(&self) -> SystemTime {
+        self.offset.apply(SystemTime::now())
+    }
+}
+
+impl core::fmt::Display for NetworkTimeSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
@@ -4,9 +4,9 @@
  * license that can be found in the LICENSE file.
  */
 
-use crate::models::Uint64;
+use crate::models::{RolesType, Uint64};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NodeInfo {
     /// The public key used to identify the node.
@@ -16,13 +16,28 @@ pub struct NodeInfo {
     pub network_identifier: u8,
     /// The version of the application.
     pub version: u16,
-    pub roles: u8,
+    /// The roles of the node, decoded from the bit field it reports.
+    pub roles: Vec<RolesType>,
     /// The IP address of the endpoint.
     pub host: String,
     /// The name of the node.
     pub friendly_name: String,
 }
 
+impl NodeInfo {
+    pub fn has_role(&self, role: RolesType) -> bool {
+        self.roles.contains(&role)
+    }
+
+    pub fn is_api_node(&self) -> bool {
+        self.has_role(RolesType::ApiNode)
+    }
+
+    pub fn is_peer_node(&self) -> bool {
+        self.has_role(RolesType::PeerNode)
+    }
+}
+
 impl core::fmt::Display for NodeInfo {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(
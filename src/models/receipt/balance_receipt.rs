@@ -0,0 +1,114 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use crate::models::{
+    account::Address,
+    mosaic::MosaicId,
+    transaction::{Amount, Height},
+};
+
+use super::ReceiptSource;
+
+/// The basic type of a receipt moving mosaics from an account to another.
+pub const RECEIPT_BASIC_TYPE_BALANCE_TRANSFER: u16 = 0x1;
+/// The basic type of a receipt crediting mosaics to an account.
+pub const RECEIPT_BASIC_TYPE_BALANCE_CREDIT: u16 = 0x2;
+/// The basic type of a receipt debiting mosaics from an account.
+pub const RECEIPT_BASIC_TYPE_BALANCE_DEBIT: u16 = 0x3;
+
+const RECEIPT_FACILITY_CORE: u16 = 0x43;
+const RECEIPT_FACILITY_LOCK_HASH: u16 = 0x48;
+const RECEIPT_FACILITY_MOSAIC: u16 = 0x4D;
+const RECEIPT_FACILITY_NAMESPACE: u16 = 0x4E;
+const RECEIPT_FACILITY_LOCK_SECRET: u16 = 0x52;
+const RECEIPT_FACILITY_EXCHANGE: u16 = 0x5D;
+
+/// The receipt debiting the mosaics locked by a new exchange offer.
+pub const RECEIPT_TYPE_OFFER_CREATED: u16 = 0x315D;
+/// The receipt crediting the mosaics received by filling an exchange offer.
+pub const RECEIPT_TYPE_OFFER_EXCHANGED: u16 = 0x225D;
+/// The receipt crediting back the remaining mosaics of an exchange offer removed by its
+/// owner or expired.
+pub const RECEIPT_TYPE_OFFER_REMOVED: u16 = 0x235D;
+
+/// What caused the mosaics of a balance receipt to move, taken from the facility code of
+/// the receipt type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BalanceReceiptKind {
+    /// Fees collected by the harvester of a block.
+    Harvest,
+    /// Hash or secret lock funds locked, released or expired.
+    Lock,
+    /// Mosaic or namespace rental fees, and mosaic levies.
+    Mosaic,
+    /// Exchange offers created, filled, removed or expired.
+    Exchange,
+    Other,
+}
+
+impl BalanceReceiptKind {
+    pub fn from_receipt_type(receipt_type: u16) -> Self {
+        match receipt_type & 0xFF {
+            RECEIPT_FACILITY_CORE => BalanceReceiptKind::Harvest,
+            RECEIPT_FACILITY_LOCK_HASH | RECEIPT_FACILITY_LOCK_SECRET => BalanceReceiptKind::Lock,
+            RECEIPT_FACILITY_MOSAIC | RECEIPT_FACILITY_NAMESPACE => BalanceReceiptKind::Mosaic,
+            RECEIPT_FACILITY_EXCHANGE => BalanceReceiptKind::Exchange,
+            _ => BalanceReceiptKind::Other,
+        }
+    }
+}
+
+/// A receipt of a block that changed the balance of one or two accounts.
+///
+/// A credit has no [from] account, a debit has no [to] account and a transfer has both.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceReceipt {
+    pub height: Height,
+    pub source: ReceiptSource,
+    pub receipt_type: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<Address>,
+    pub mosaic_id: MosaicId,
+    pub amount: Amount,
+}
+
+impl BalanceReceipt {
+    /// The basic type of the receipt, one of the `RECEIPT_BASIC_TYPE_*` constants.
+    pub fn basic_type(&self) -> u16 {
+        self.receipt_type >> 12
+    }
+
+    pub fn kind(&self) -> BalanceReceiptKind {
+        BalanceReceiptKind::from_receipt_type(self.receipt_type)
+    }
+
+    /// The change of the balance of [address] caused by the receipt.
+    pub fn delta_for(&self, address: &Address) -> i128 {
+        let amount = i128::from(*self.amount);
+
+        let mut delta = 0;
+        if self.from.as_ref() == Some(address) {
+            delta -= amount;
+        }
+        if self.to.as_ref() == Some(address) {
+            delta += amount;
+        }
+        delta
+    }
+}
+
+impl core::fmt::Display for BalanceReceipt {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(&self).unwrap_or_default()
+        )
+    }
+}
@@ -0,0 +1,225 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use std::fmt;
+
+use crate::{
+    models::{
+        account::Address,
+        errors_const,
+        mosaic::MosaicId,
+        transaction::{
+            AggregateTransaction, Height, LockFundsTransaction, Transaction, TransactionInfo,
+            TransferTransaction,
+        },
+        AssetId, AssetIdType,
+    },
+    Result,
+};
+
+/// The transaction that triggered a receipt.
+///
+/// `primary_id` is the 1-based index of the transaction within the block, `secondary_id` is the
+/// 1-based index of the inner transaction within an aggregate, or 0 for any other transaction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceiptSource {
+    pub primary_id: u32,
+    pub secondary_id: u32,
+}
+
+impl ReceiptSource {
+    pub fn new(primary_id: u32, secondary_id: u32) -> Self {
+        ReceiptSource {
+            primary_id,
+            secondary_id,
+        }
+    }
+
+    /// The source of a transaction that is not part of an aggregate.
+    pub fn from_transaction_info(info: &TransactionInfo) -> Self {
+        Self::new(info.index + 1, 0)
+    }
+
+    /// The source of an inner transaction, given the info of the aggregate containing it
+    /// and its own info.
+    pub fn from_inner_transaction_info(
+        aggregate_info: &TransactionInfo,
+        inner_info: &TransactionInfo,
+    ) -> Self {
+        Self::new(aggregate_info.index + 1, inner_info.index + 1)
+    }
+}
+
+/// The value a namespace alias resolved to, starting at the given source.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolutionEntry<T> {
+    pub source: ReceiptSource,
+    pub resolved: T,
+}
+
+/// Keeps the relation between a namespace alias used in the transactions of a block and the
+/// real value it referred to.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolutionStatement<U, T> {
+    pub height: Height,
+    pub unresolved: U,
+    /// The entries are ordered by source. Within one block the resolution may change for
+    /// different sources due to alias related transactions.
+    pub resolution_entries: Vec<ResolutionEntry<T>>,
+}
+
+impl<U, T: Clone> ResolutionStatement<U, T> {
+    /// The value the alias referred to for the transaction of the given [source].
+    ///
+    /// That is the latest entry whose source is not after the given one.
+    pub fn resolve(&self, source: ReceiptSource) -> Option<T> {
+        self.resolution_entries
+            .iter()
+            .filter(|entry| entry.source <= source)
+            .max_by_key(|entry| entry.source)
+            .map(|entry| entry.resolved.clone())
+    }
+}
+
+pub type AddressResolutionStatement = ResolutionStatement<Address, Address>;
+
+pub type MosaicResolutionStatement = ResolutionStatement<u64, MosaicId>;
+
+/// The address and mosaic resolutions of a block.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockResolutions {
+    pub height: Height,
+    pub address_resolution_statements: Vec<AddressResolutionStatement>,
+    pub mosaic_resolution_statements: Vec<MosaicResolutionStatement>,
+}
+
+impl BlockResolutions {
+    /// The account address a namespace alias [address] referred to for the transaction of
+    /// the given [source].
+    ///
+    /// An account address is returned as it is.
+    pub fn resolve_address(&self, address: &Address, source: ReceiptSource) -> Option<Address> {
+        if !address.is_namespace_alias() {
+            return Some(*address);
+        }
+
+        self.address_resolution_statements
+            .iter()
+            .find(|statement| statement.unresolved.as_bytes() == address.as_bytes())
+            .and_then(|statement| statement.resolve(source))
+    }
+
+    /// The mosaic a namespace [asset_id] referred to for the transaction of the given [source].
+    ///
+    /// A mosaic id is returned as it is.
+    pub fn resolve_mosaic_id(
+        &self,
+        asset_id: &dyn AssetId,
+        source: ReceiptSource,
+    ) -> Option<MosaicId> {
+        if asset_id.get_type() == AssetIdType::MosaicIdType {
+            return Some(MosaicId::from(asset_id.to_u64()));
+        }
+
+        self.mosaic_resolution_statements
+            .iter()
+            .find(|statement| statement.unresolved == asset_id.to_u64())
+            .and_then(|statement| statement.resolve(source))
+    }
+}
+
+impl BlockResolutions {
+    /// Resolves the namespace aliases of a confirmed transaction of this block.
+    ///
+    /// Returns a copy of the transaction where the aliased recipient and mosaics of transfers,
+    /// and the aliased mosaic of lock funds transactions, are replaced by the values they
+    /// referred to when the transaction was confirmed. Inner transactions of an aggregate
+    /// are resolved too, any other transaction is returned as it is.
+    pub fn resolve_transaction(
+        &self,
+        transaction: &dyn Transaction,
+    ) -> Result<Box<dyn Transaction>> {
+        let info = match transaction.abs_transaction().transaction_info {
+            Some(info) if *info.height > 0 => info,
+            _ => bail!(errors_const::ERR_TRANSACTION_NOT_CONFIRMED),
+        };
+
+        if let Some(aggregate) = transaction.try_downcast_ref::<AggregateTransaction>() {
+            let mut aggregate = aggregate.clone();
+            for (position, inner) in aggregate.inner_transactions.iter_mut().enumerate() {
+                let source = match inner.abs_transaction().transaction_info {
+                    Some(inner_info) => {
+                        ReceiptSource::from_inner_transaction_info(&info, &inner_info)
+                    }
+                    None => ReceiptSource::new(info.index + 1, position as u32 + 1),
+                };
+                *inner = self.resolve_transaction_with_source(inner.as_ref(), source)?;
+            }
+            return Ok(Box::new(aggregate));
+        }
+
+        self.resolve_transaction_with_source(
+            transaction,
+            ReceiptSource::from_transaction_info(&info),
+        )
+    }
+
+    fn resolve_transaction_with_source(
+        &self,
+        transaction: &dyn Transaction,
+        source: ReceiptSource,
+    ) -> Result<Box<dyn Transaction>> {
+        if let Some(transfer) = transaction.try_downcast_ref::<TransferTransaction>() {
+            let mut transfer = transfer.clone();
+
+            transfer.recipient = match self.resolve_address(&transfer.recipient, source) {
+                Some(address) => address,
+                None => bail!(errors_const::ERR_UNRESOLVED_ALIAS),
+            };
+
+            for mosaic in transfer.mosaics.iter_mut() {
+                mosaic.asset_id =
+                    Box::new(self.resolve_mosaic_id_or_err(mosaic.asset_id.as_ref(), source)?);
+            }
+
+            return Ok(Box::new(transfer));
+        }
+
+        if let Some(lock) = transaction.try_downcast_ref::<LockFundsTransaction>() {
+            let mut lock = lock.clone();
+            lock.mosaic.asset_id =
+                Box::new(self.resolve_mosaic_id_or_err(lock.mosaic.asset_id.as_ref(), source)?);
+            return Ok(Box::new(lock));
+        }
+
+        Ok(transaction.box_clone())
+    }
+
+    fn resolve_mosaic_id_or_err(
+        &self,
+        asset_id: &dyn AssetId,
+        source: ReceiptSource,
+    ) -> Result<MosaicId> {
+        match self.resolve_mosaic_id(asset_id, source) {
+            Some(mosaic_id) => Ok(mosaic_id),
+            None => bail!(errors_const::ERR_UNRESOLVED_ALIAS),
+        }
+    }
+}
+
+impl fmt::Display for BlockResolutions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).unwrap_or_default()
+        )
+    }
+}
@@ -5,10 +5,39 @@
 /// The role of the node:
 ///* 1 - A peer node.
 ///* 2 - An api node.
-#[derive(Serialize, Deserialize)]
+///
+/// A node reports its roles as a bit field, which [RolesType::from_bits] decodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[repr(u8)]
 pub enum RolesType {
-    #[serde(rename = "1")]
-    _1,
-    #[serde(rename = "2")]
-    _2,
+    PeerNode = 0x01,
+    ApiNode = 0x02,
+}
+
+impl RolesType {
+    pub const ALL: [RolesType; 2] = [RolesType::PeerNode, RolesType::ApiNode];
+
+    pub fn value(self) -> u8 {
+        self as u8
+    }
+
+    /// Decodes the roles set in [bits]. Unknown bits are ignored.
+    pub fn from_bits(bits: u8) -> Vec<RolesType> {
+        Self::ALL
+            .iter()
+            .filter(|role| bits & role.value() != 0)
+            .copied()
+            .collect()
+    }
+
+    /// Encodes [roles] into a bit field.
+    pub fn to_bits(roles: &[RolesType]) -> u8 {
+        roles.iter().fold(0, |bits, role| bits | role.value())
+    }
+}
+
+impl core::fmt::Display for RolesType {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{:?}", self)
+    }
 }
@@ -11,17 +11,23 @@ use {
     },
     chrono::{
         prelude::DateTime,
-        {Local, NaiveTime, Timelike},
+        {Local, NaiveTime, SecondsFormat, TimeZone, Timelike, Utc},
     },
-    serde::{Serialize, Serializer},
+    serde::{de::Error as DeError, Deserialize, Deserializer, Serialize, Serializer},
 };
 
-use crate::models::Uint64;
+use crate::{
+    errors_const::{ERR_DEADLINE_EXPIRED, ERR_DEADLINE_TOO_FAR, ERR_INVALID_DEADLINE_DURATION},
+    models::Uint64,
+};
 
 /// It is Friday, 01 April 2016 00:00:00 +0000 UTC (1459468800000 milliseconds since the epoch time).
-const TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS: i64 = 1_459_468_800 * 1_000;
+pub(crate) const TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS: i64 = 1_459_468_800 * 1_000;
 const MILLISECONDS: i64 = 1_000_000;
 
+/// The default maximum time between the announcement of a transaction and its deadline.
+pub const MAX_DEADLINE_WINDOW: Duration = Duration::from_secs(24 * 60 * 60);
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BlockchainTimestamp(i64);
 
@@ -32,7 +38,13 @@ impl BlockchainTimestamp {
 
     /// returns new timestamp from passed milliseconds value
     pub fn to_timestamp(&self) -> Timestamp {
-        Timestamp::new(self.0 + TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS)
+        self.to_timestamp_with_epoch(TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS)
+    }
+
+    /// returns new timestamp on a network whose time counts from [epoch], in milliseconds
+    /// since the Unix epoch
+    pub fn to_timestamp_with_epoch(&self, epoch: i64) -> Timestamp {
+        Timestamp::new(self.0 + epoch)
     }
 
     pub fn to_uint64(&self) -> Uint64 {
@@ -51,9 +63,15 @@ impl Timestamp {
     }
 
     pub fn to_blockchain_timestamp(&self) -> BlockchainTimestamp {
+        self.to_blockchain_timestamp_with_epoch(TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS)
+    }
+
+    /// returns the timestamp on a network whose time counts from [epoch], in milliseconds
+    /// since the Unix epoch
+    pub fn to_blockchain_timestamp_with_epoch(&self, epoch: i64) -> BlockchainTimestamp {
         BlockchainTimestamp(
             (self.0.duration_since(UNIX_EPOCH).unwrap().as_nanos() / MILLISECONDS as u128) as i64
-                - TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS,
+                - epoch,
         )
     }
 
@@ -81,22 +99,159 @@ impl core::fmt::Display for Timestamp {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)] // we derive Default in order to use the clear() method in Drop
-pub struct Deadline(Timestamp);
+/// A source of the current time, used to create a [Deadline].
+pub trait TimeSource {
+    fn now(&self) -> SystemTime;
+}
+
+/// The clock of the local system.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SystemTimeSource;
+
+impl TimeSource for SystemTimeSource {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// The time after which a transaction is rejected by the network.
+///
+/// A `Deadline` serializes to its instant, as an RFC 3339 string in UTC with as many
+/// fractional digits as needed, and to the network epoch it is encoded with, so it
+/// deserializes back to the exact same deadline. A bare RFC 3339 string is read on the
+/// default epoch.
+///
+/// The constructors taking no epoch encode the deadline on the epoch of the ProximaX
+/// public networks. On a network with another epoch, create deadlines with
+/// `SiriusClient::deadline` or `NetworkProperties::deadline`, or move them with
+/// [Deadline::with_epoch]; the client refuses to announce a transaction whose deadline
+/// is only valid on the default epoch.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Deadline(Timestamp, i64);
 
 impl Deadline {
     pub fn new(hour: u8, minute: u8, second: u64) -> Self {
-        let time_now = SystemTime::now();
+        Self::new_with_time_source(&SystemTimeSource, hour, minute, second)
+    }
+
+    /// Creates a `Deadline` relative to the current time of [time_source], such as a
+    /// `NetworkTimeSource` following the clock of a node.
+    pub fn new_with_time_source<T: TimeSource + ?Sized>(
+        time_source: &T,
+        hour: u8,
+        minute: u8,
+        second: u64,
+    ) -> Self {
+        let time_now = time_source.now();
         let _hour: u64 = hour as u64 * 3600;
         let _minute: u64 = minute as u64 * 60;
 
-        Deadline(Timestamp(
-            time_now.add(Duration::from_secs(_hour + _minute + second)),
-        ))
+        Deadline(
+            Timestamp(time_now.add(Duration::from_secs(_hour + _minute + second))),
+            TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS,
+        )
+    }
+
+    /// Creates a `Deadline` [duration] from now.
+    pub fn from_duration(duration: chrono::Duration) -> crate::Result<Self> {
+        Self::from_duration_with_time_source(&SystemTimeSource, duration)
+    }
+
+    /// Creates a `Deadline` [duration] from the current time of [time_source].
+    pub fn from_duration_with_time_source<T: TimeSource + ?Sized>(
+        time_source: &T,
+        duration: chrono::Duration,
+    ) -> crate::Result<Self> {
+        let time_now = DateTime::<Utc>::from(time_source.now());
+
+        match time_now.checked_add_signed(duration) {
+            Some(date_time) => Ok(Self::from_date_time(date_time)),
+            None => bail!(ERR_INVALID_DEADLINE_DURATION),
+        }
+    }
+
+    /// Creates a `Deadline` at an absolute [date_time], in any time zone.
+    pub fn from_date_time<Tz: TimeZone>(date_time: DateTime<Tz>) -> Self {
+        Deadline(
+            Timestamp(SystemTime::from(date_time)),
+            TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS,
+        )
+    }
+
+    pub fn from_blockchain_timestamp(timestamp: BlockchainTimestamp) -> Self {
+        Self::from_blockchain_timestamp_with_epoch(timestamp, TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS)
+    }
+
+    /// Creates a `Deadline` from a [timestamp] of a network whose time counts from
+    /// [epoch], in milliseconds since the Unix epoch.
+    pub fn from_blockchain_timestamp_with_epoch(
+        timestamp: BlockchainTimestamp,
+        epoch: i64,
+    ) -> Self {
+        Deadline(timestamp.to_timestamp_with_epoch(epoch), epoch)
+    }
+
+    /// Returns the same deadline on a network whose time counts from [epoch], in
+    /// milliseconds since the Unix epoch, such as `NetworkProperties::epoch`.
+    pub fn with_epoch(self, epoch: i64) -> Self {
+        Deadline(self.0, epoch)
+    }
+
+    /// The time, in milliseconds since the Unix epoch, the network time counts from.
+    pub fn epoch(&self) -> i64 {
+        self.1
+    }
+
+    pub fn to_date_time(&self) -> DateTime<Utc> {
+        DateTime::<Utc>::from((self.0).0)
+    }
+
+    /// The time left until the deadline, negative once it expired.
+    pub fn remaining(&self) -> chrono::Duration {
+        self.remaining_with_time_source(&SystemTimeSource)
+    }
+
+    /// The time left until the deadline on the clock of [time_source], negative once it
+    /// expired.
+    pub fn remaining_with_time_source<T: TimeSource + ?Sized>(
+        &self,
+        time_source: &T,
+    ) -> chrono::Duration {
+        self.to_date_time()
+            .signed_duration_since(DateTime::<Utc>::from(time_source.now()))
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.remaining() <= chrono::Duration::zero()
+    }
+
+    /// Checks the deadline is in the future and at most [max_window] from now, such as
+    /// `MAX_DEADLINE_WINDOW`.
+    pub fn validate(&self, max_window: Duration) -> crate::Result<()> {
+        self.validate_with_time_source(&SystemTimeSource, max_window)
+    }
+
+    /// Checks the deadline is in the future and at most [max_window] from the current
+    /// time of [time_source].
+    pub fn validate_with_time_source<T: TimeSource + ?Sized>(
+        &self,
+        time_source: &T,
+        max_window: Duration,
+    ) -> crate::Result<()> {
+        let remaining = self.remaining_with_time_source(time_source);
+        ensure!(remaining > chrono::Duration::zero(), ERR_DEADLINE_EXPIRED);
+
+        let too_far = match chrono::Duration::from_std(max_window) {
+            Ok(max_window) => remaining > max_window,
+            Err(_) => false,
+        };
+        ensure!(!too_far, ERR_DEADLINE_TOO_FAR);
+
+        Ok(())
     }
 
     pub fn to_blockchain_timestamp(&self) -> BlockchainTimestamp {
-        self.0.to_blockchain_timestamp()
+        self.0.to_blockchain_timestamp_with_epoch(self.1)
     }
 
     pub fn to_uint64(&self) -> Uint64 {
@@ -116,7 +271,51 @@ impl core::fmt::Display for Deadline {
 
 impl From<BlockchainTimestamp> for Deadline {
     fn from(e: BlockchainTimestamp) -> Self {
-        Deadline(e.to_timestamp())
+        Deadline::from_blockchain_timestamp(e)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct DeadlineDto {
+    time: String,
+    epoch: i64,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DeadlineRepr {
+    Deadline(DeadlineDto),
+    Time(String),
+}
+
+impl Serialize for Deadline {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        DeadlineDto {
+            time: self
+                .to_date_time()
+                .to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            epoch: self.epoch(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Deadline {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (time, epoch) = match DeadlineRepr::deserialize(deserializer)? {
+            DeadlineRepr::Deadline(dto) => (dto.time, dto.epoch),
+            DeadlineRepr::Time(time) => (time, TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS),
+        };
+
+        DateTime::parse_from_rfc3339(&time)
+            .map(|date_time| Deadline::from_date_time(date_time).with_epoch(epoch))
+            .map_err(DeError::custom)
     }
 }
 
@@ -21,6 +21,7 @@ pub use self::transaction_alias::*;
 pub use self::transaction_alias_address::*;
 pub use self::transaction_alias_mosaic::*;
 pub use self::transaction_exchange_offer::*;
+pub use self::transaction_fee::*;
 pub use self::transaction_hash_lock::*;
 pub use self::transaction_info::*;
 pub use self::transaction_model::*;
@@ -54,6 +55,7 @@ mod transaction_alias;
 mod transaction_alias_address;
 mod transaction_alias_mosaic;
 mod transaction_exchange_offer;
+mod transaction_fee;
 mod transaction_hash_lock;
 mod transaction_info;
 mod transaction_model;
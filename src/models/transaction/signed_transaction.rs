@@ -6,7 +6,11 @@
 
 use serde_json::Value;
 
-use super::{HashValue, TransactionType};
+use super::{BlockchainTimestamp, HashValue, TransactionType};
+
+/// The offset of the deadline in a transaction payload, after its size, signature, signer,
+/// version, type and maximum fee.
+const PAYLOAD_DEADLINE_OFFSET: usize = 4 + 64 + 32 + 4 + 2 + 8;
 
 /// Used to transfer the transaction data and the signature to a nem server in order to
 /// initiate and broadcast a transaction.
@@ -49,6 +53,17 @@ impl SignedTransaction {
         hex::decode(&self.get_payload()).unwrap()
     }
 
+    /// The deadline encoded in the payload, on the epoch of the network it was built for.
+    pub(crate) fn payload_deadline(&self) -> Option<BlockchainTimestamp> {
+        let payload = hex::decode(self.payload.as_ref()?).ok()?;
+        let bytes = payload.get(PAYLOAD_DEADLINE_OFFSET..PAYLOAD_DEADLINE_OFFSET + 8)?;
+
+        let mut deadline = [0u8; 8];
+        deadline.copy_from_slice(bytes);
+
+        Some(BlockchainTimestamp::new(u64::from_le_bytes(deadline) as i64))
+    }
+
     pub fn get_payload(&self) -> String {
         match self.payload.to_owned() {
             Some(payload) => payload,
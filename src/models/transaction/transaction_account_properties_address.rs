@@ -16,7 +16,7 @@ use crate::{
         ACCOUNT_PROPERTIES_ADDRESS_MODIFICATION_SIZE, ACCOUNT_PROPERTY_ADDRESS_HEADER,
     },
     network::NetworkType,
-    Result,
+    Result, Uint64,
 };
 
 use super::{
@@ -141,6 +141,10 @@ impl Transaction for AccountPropertiesAddressTransaction {
         self.abs_transaction.set_aggregate(signer)
     }
 
+    fn set_max_fee(&mut self, max_fee: Uint64) {
+        self.abs_transaction.set_max_fee(max_fee)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -19,7 +19,7 @@ use crate::{
     },
     network::NetworkType,
     transaction::ACCOUNT_PROPERTY_ENTITY_TYPE_VERSION,
-    Result,
+    Result, Uint64,
 };
 
 use super::{
@@ -144,6 +144,10 @@ impl Transaction for AccountPropertiesEntityTypeTransaction {
         self.abs_transaction.set_aggregate(signer)
     }
 
+    fn set_max_fee(&mut self, max_fee: Uint64) {
+        self.abs_transaction.set_max_fee(max_fee)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
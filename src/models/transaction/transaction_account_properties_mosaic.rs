@@ -14,7 +14,7 @@ use crate::{
     account::{Account, AccountPropertiesMosaicModification, AccountPropertyType, PublicAccount},
     models::consts::{ACCOUNT_PROPERTIES_MOSAIC_MODIFICATION_SIZE, ACCOUNT_PROPERTY_MOSAIC_HEADER},
     network::NetworkType,
-    Result,
+    Result, Uint64,
 };
 
 use super::{
@@ -139,6 +139,10 @@ impl Transaction for AccountPropertiesMosaicTransaction {
         self.abs_transaction.set_aggregate(signer)
     }
 
+    fn set_max_fee(&mut self, max_fee: Uint64) {
+        self.abs_transaction.set_max_fee(max_fee)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -111,6 +111,10 @@ impl Transaction for AddExchangeOfferTransaction {
         self.abs_transaction.set_aggregate(signer)
     }
 
+    fn set_max_fee(&mut self, max_fee: Uint64) {
+        self.abs_transaction.set_max_fee(max_fee)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
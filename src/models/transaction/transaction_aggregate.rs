@@ -9,22 +9,27 @@ use {::std::fmt, failure::_core::any::Any, serde_json::Value};
 use crate::{
     models::{
         account::{Account, PublicAccount},
-        consts::{AGGREGATE_BONDED_HEADER, DEAD_LINE_SIZE, MAX_FEE_SIZE, SIGNATURE_SIZE},
+        consts::{
+            AGGREGATE_BONDED_HEADER, COSIGNATURE_SIZE, DEAD_LINE_SIZE, MAX_FEE_SIZE, SIGNATURE_SIZE,
+        },
         errors_const::ERR_EMPTY_INNER_TRANSACTION,
         multisig::Cosignature,
         network::NetworkType,
+        uint_64::Uint64,
     },
     Result,
 };
 
 use super::{
     buffer::aggregate as buffer,
+    calculate_max_fee,
     internal::{
         sign_transaction, sign_transaction_with_cosignatures, to_aggregate_transaction_bytes,
     },
     schema::aggregate_transaction_schema,
-    AbsTransaction, AbstractTransaction, Deadline, HashValue, SignedTransaction, Transaction,
-    TransactionType, Transactions, AGGREGATE_BONDED_VERSION, AGGREGATE_COMPLETED_VERSION,
+    AbsTransaction, AbstractTransaction, Deadline, FeeMultiplier, HashValue, SignedTransaction,
+    Transaction, TransactionType, Transactions, AGGREGATE_BONDED_VERSION,
+    AGGREGATE_COMPLETED_VERSION,
 };
 
 /// AggregateTransaction:
@@ -81,6 +86,23 @@ impl AggregateTransaction {
         })
     }
 
+    /// The size of this transaction once [cosignatures] cosignatures are attached to it.
+    pub fn size_with_cosignatures(&self, cosignatures: usize) -> usize {
+        self.size() + cosignatures * COSIGNATURE_SIZE
+    }
+
+    /// Sets the maximum fee for this aggregate, counting the size of the
+    /// [required_cosignatures] cosignatures expected to be attached to it.
+    pub fn set_max_fee_for_aggregate(
+        &mut self,
+        fee_multiplier: FeeMultiplier,
+        required_cosignatures: usize,
+    ) {
+        let size = self.size_with_cosignatures(required_cosignatures);
+        self.abs_transaction
+            .set_max_fee(calculate_max_fee(size, fee_multiplier))
+    }
+
     pub(crate) fn sign_with_cosignatories(
         self,
         account: Account,
@@ -168,6 +190,10 @@ impl Transaction for AggregateTransaction {
         self.abs_transaction.set_aggregate(signer)
     }
 
+    fn set_max_fee(&mut self, max_fee: Uint64) {
+        self.abs_transaction.set_max_fee(max_fee)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -6,7 +6,7 @@
 
 use crate::models::{
     account::PublicAccount, alias::AliasActionType, asset_id_model::AssetId,
-    consts::ALIAS_TRANSACTION_HEADER, namespace::NamespaceId,
+    consts::ALIAS_TRANSACTION_HEADER, namespace::NamespaceId, uint_64::Uint64,
 };
 
 use super::{buffer::alias as buffer, schema::alias_transaction_schema, AbstractTransaction};
@@ -24,6 +24,10 @@ impl AliasTransaction {
         self.abs_transaction.set_aggregate(signer)
     }
 
+    pub(crate) fn set_max_fee(&mut self, max_fee: Uint64) {
+        self.abs_transaction.set_max_fee(max_fee)
+    }
+
     pub(crate) fn abs_transaction(&self) -> AbstractTransaction {
         self.abs_transaction.to_owned()
     }
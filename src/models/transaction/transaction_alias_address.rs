@@ -18,6 +18,7 @@ use crate::{
         errors_const,
         namespace::NamespaceId,
         network::NetworkType,
+        uint_64::Uint64,
     },
     Result,
 };
@@ -107,6 +108,10 @@ impl Transaction for AddressAliasTransaction {
         self.alias_transaction.set_aggregate(signer)
     }
 
+    fn set_max_fee(&mut self, max_fee: Uint64) {
+        self.alias_transaction.set_max_fee(max_fee)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -19,6 +19,7 @@ use crate::{
         mosaic::MosaicId,
         namespace::NamespaceId,
         network::NetworkType,
+        uint_64::Uint64,
     },
     Result,
 };
@@ -108,6 +109,10 @@ impl Transaction for MosaicAliasTransaction {
         self.alias_transaction.set_aggregate(signer)
     }
 
+    fn set_max_fee(&mut self, max_fee: Uint64) {
+        self.alias_transaction.set_max_fee(max_fee)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -14,6 +14,7 @@ use crate::{
         errors_const,
         exchange::ExchangeConfirmation,
         network::NetworkType,
+        uint_64::Uint64,
     },
     Result,
 };
@@ -111,6 +112,10 @@ impl Transaction for ExchangeOfferTransaction {
         self.abs_transaction.set_aggregate(signer)
     }
 
+    fn set_max_fee(&mut self, max_fee: Uint64) {
+        self.abs_transaction.set_max_fee(max_fee)
+    }
+
     fn box_clone(&self) -> Box<dyn Transaction + 'static> {
         Box::new((*self).clone())
     }
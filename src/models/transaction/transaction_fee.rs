@@ -0,0 +1,38 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use crate::models::{blockchain::BlockInfo, uint_64::Uint64};
+
+/// The fee paid per byte of a transaction.
+pub type FeeMultiplier = u32;
+
+/// Calculates the maximum fee of a transaction of [size] bytes for the given [fee_multiplier].
+pub fn calculate_max_fee(size: usize, fee_multiplier: FeeMultiplier) -> Uint64 {
+    Uint64::new(size as u64 * u64::from(fee_multiplier))
+}
+
+/// Returns the median fee multiplier of the given blocks, or zero when there are none.
+///
+/// The median is used rather than the average, so a single block with an unusual fee
+/// multiplier does not skew the result.
+pub fn median_fee_multiplier(blocks: &[BlockInfo]) -> FeeMultiplier {
+    let mut multipliers: Vec<FeeMultiplier> = blocks
+        .iter()
+        .map(|block| block.fee_multiplier.max(0) as FeeMultiplier)
+        .collect();
+
+    if multipliers.is_empty() {
+        return 0;
+    }
+
+    multipliers.sort_unstable();
+
+    // Both indexes point to the same element for an odd number of blocks.
+    let lower = u64::from(multipliers[(multipliers.len() - 1) / 2]);
+    let upper = u64::from(multipliers[multipliers.len() / 2]);
+
+    ((lower + upper) / 2) as FeeMultiplier
+}
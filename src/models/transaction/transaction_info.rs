@@ -110,6 +110,10 @@ impl AbstractTransaction {
         self.signer = signer;
     }
 
+    pub(crate) fn set_max_fee(&mut self, max_fee: Uint64) {
+        self.max_fee = Some(max_fee);
+    }
+
     pub(crate) fn build_vector<'a>(
         &self,
         builder: &mut fb::FlatBufferBuilder<'a>,
@@ -16,7 +16,8 @@ use crate::models::{
 };
 
 use super::{
-    deadline::Deadline, AbstractTransaction, HashValue, SignedTransaction, TransactionType,
+    calculate_max_fee, deadline::Deadline, AbstractTransaction, FeeMultiplier, HashValue,
+    SignedTransaction, TransactionType,
 };
 
 pub type Amount = Uint64;
@@ -180,6 +181,15 @@ where
 
     fn set_aggregate(&mut self, signer: PublicAccount);
 
+    /// Sets the maximum fee allowed to be spent for this transaction.
+    fn set_max_fee(&mut self, max_fee: Uint64);
+
+    /// Sets the maximum fee to the size of this transaction times the [fee_multiplier].
+    fn set_max_fee_for(&mut self, fee_multiplier: FeeMultiplier) {
+        let max_fee = calculate_max_fee(self.size(), fee_multiplier);
+        self.set_max_fee(max_fee)
+    }
+
     fn as_any(&self) -> &dyn Any;
 
     fn into_any(self: Box<Self>) -> Box<dyn Any>;
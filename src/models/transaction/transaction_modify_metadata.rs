@@ -8,6 +8,7 @@ use crate::models::{
     account::PublicAccount,
     consts::METADATA_HEADER_SIZE,
     metadata::{MetadataModification, MetadataType},
+    uint_64::Uint64,
 };
 
 use super::{
@@ -28,6 +29,10 @@ impl ModifyMetadataTransaction {
         self.abs_transaction.set_aggregate(signer)
     }
 
+    pub(crate) fn set_max_fee(&mut self, max_fee: Uint64) {
+        self.abs_transaction.set_max_fee(max_fee)
+    }
+
     pub(crate) fn abs_transaction(&self) -> AbstractTransaction {
         self.abs_transaction.to_owned()
     }
@@ -13,9 +13,9 @@ use crate::{
     models::{
         account::{Account, Address, PublicAccount},
         consts::ADDRESS_SIZE,
-        errors_const,
-        metadata::{MetadataModification, MetadataType},
+        metadata::{validate_metadata_modifications, MetadataModification, MetadataType},
         network::NetworkType,
+        uint_64::Uint64,
     },
     Result,
 };
@@ -40,10 +40,7 @@ impl MetadataAddressTransaction {
         modifications: Vec<MetadataModification>,
         network_type: NetworkType,
     ) -> Result<Self> {
-        ensure!(
-            !modifications.is_empty(),
-            errors_const::ERR_METADATA_EMPTY_MODIFICATIONS
-        );
+        validate_metadata_modifications(&modifications)?;
 
         let abs_tx = AbstractTransaction::new_from_type(
             deadline,
@@ -103,6 +100,10 @@ impl Transaction for MetadataAddressTransaction {
         self.metadata_transaction.set_aggregate(signer)
     }
 
+    fn set_max_fee(&mut self, max_fee: Uint64) {
+        self.metadata_transaction.set_max_fee(max_fee)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
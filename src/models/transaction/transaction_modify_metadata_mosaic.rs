@@ -13,10 +13,10 @@ use crate::{
     models::{
         account::{Account, PublicAccount},
         consts::MOSAIC_ID_SIZE,
-        errors_const,
-        metadata::{MetadataModification, MetadataType},
+        metadata::{validate_metadata_modifications, MetadataModification, MetadataType},
         mosaic::MosaicId,
         network::NetworkType,
+        uint_64::Uint64,
     },
     AssetId, Result,
 };
@@ -41,10 +41,7 @@ impl MetadataMosaicTransaction {
         modifications: Vec<MetadataModification>,
         network_type: NetworkType,
     ) -> Result<Self> {
-        ensure!(
-            !modifications.is_empty(),
-            errors_const::ERR_METADATA_EMPTY_MODIFICATIONS
-        );
+        validate_metadata_modifications(&modifications)?;
 
         let abs_tx = AbstractTransaction::new_from_type(
             deadline,
@@ -104,6 +101,10 @@ impl Transaction for MetadataMosaicTransaction {
         self.metadata_transaction.set_aggregate(signer)
     }
 
+    fn set_max_fee(&mut self, max_fee: Uint64) {
+        self.metadata_transaction.set_max_fee(max_fee)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -13,10 +13,10 @@ use crate::{
     models::{
         account::{Account, PublicAccount},
         consts::NAMESPACE_SIZE,
-        errors_const,
-        metadata::{MetadataModification, MetadataType},
+        metadata::{validate_metadata_modifications, MetadataModification, MetadataType},
         namespace::NamespaceId,
         network::NetworkType,
+        uint_64::Uint64,
     },
     AssetId, Result,
 };
@@ -41,10 +41,7 @@ impl MetadataNamespaceTransaction {
         modifications: Vec<MetadataModification>,
         network_type: NetworkType,
     ) -> Result<Self> {
-        ensure!(
-            !modifications.is_empty(),
-            errors_const::ERR_METADATA_EMPTY_MODIFICATIONS
-        );
+        validate_metadata_modifications(&modifications)?;
 
         let abs_tx = AbstractTransaction::new_from_type(
             deadline,
@@ -107,6 +104,10 @@ impl Transaction for MetadataNamespaceTransaction {
         self.metadata_transaction.set_aggregate(signer)
     }
 
+    fn set_max_fee(&mut self, max_fee: Uint64) {
+        self.metadata_transaction.set_max_fee(max_fee)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -13,6 +13,7 @@ use crate::{
         errors_const::ERR_EMPTY_MODIFICATIONS,
         multisig::CosignatoryModification,
         network::NetworkType,
+        uint_64::Uint64,
     },
     Result,
 };
@@ -43,7 +44,7 @@ impl ModifyMultisigAccountTransaction {
         network_type: NetworkType,
     ) -> Result<Self> {
         ensure!(
-            !modifications.is_empty() && min_approval_delta != 0 && min_removal_delta != 0,
+            !modifications.is_empty() || min_approval_delta != 0 || min_removal_delta != 0,
             ERR_EMPTY_MODIFICATIONS
         );
 
@@ -129,6 +130,10 @@ impl Transaction for ModifyMultisigAccountTransaction {
         self.abs_transaction.set_aggregate(signer)
     }
 
+    fn set_max_fee(&mut self, max_fee: Uint64) {
+        self.abs_transaction.set_max_fee(max_fee)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -12,7 +12,10 @@ use crate::{
         asset_id_model::AssetId,
         consts::REGISTER_NAMESPACE_HEADER_SIZE,
         errors_const,
-        namespace::{generate_namespace_id, NamespaceId, NamespaceType},
+        namespace::{
+            generate_namespace_id, generate_namespace_path, NamespaceId, NamespaceInfo,
+            NamespaceType,
+        },
         network::NetworkType,
         uint_64::Uint64,
     },
@@ -21,8 +24,9 @@ use crate::{
 
 use super::{
     buffer::register_namespace as buffer, internal::sign_transaction,
-    schema::register_namespace_transaction_schema, AbsTransaction, AbstractTransaction, Deadline,
-    HashValue, SignedTransaction, Transaction, TransactionType, REGISTER_NAMESPACE_VERSION,
+    schema::register_namespace_transaction_schema, AbsTransaction, AbstractTransaction,
+    AggregateTransaction, Deadline, HashValue, SignedTransaction, Transaction, TransactionType,
+    REGISTER_NAMESPACE_VERSION,
 };
 
 #[derive(Clone, Debug, Serialize)]
@@ -71,7 +75,7 @@ impl RegisterNamespaceTransaction {
 
     pub fn create_sub(
         deadline: Deadline,
-        namespace_name: &'static str,
+        namespace_name: &str,
         parent_id: NamespaceId,
         network_type: NetworkType,
     ) -> Result<Self> {
@@ -103,6 +107,77 @@ impl RegisterNamespaceTransaction {
             parent_id: Some(parent_id),
         })
     }
+
+    /// Creates the transaction that extends the rental of the root namespace [namespace_info]
+    /// by [duration] blocks.
+    ///
+    /// The [namespace_name] must be the name of the namespace.
+    pub fn create_renewal(
+        deadline: Deadline,
+        namespace_name: &str,
+        namespace_info: &NamespaceInfo,
+        duration: Uint64,
+        network_type: NetworkType,
+    ) -> Result<Self> {
+        ensure!(
+            namespace_info.depth == 1,
+            errors_const::ERR_NAMESPACE_NOT_ROOT
+        );
+
+        ensure!(*duration > 0, errors_const::ERR_INVALID_RENEWAL_DURATION);
+
+        ensure!(
+            NamespaceId::from_name(namespace_name)? == namespace_info.namespace_id,
+            errors_const::ERR_NAMESPACE_NAME_MISMATCH
+        );
+
+        Self::create_root(deadline, namespace_name, duration, network_type)
+    }
+
+    /// Creates the transactions that register every level of a dotted name like `a.b.c`,
+    /// the root namespace first.
+    ///
+    /// The [duration] is the rental duration of the root namespace.
+    pub fn create_path(
+        deadline: Deadline,
+        full_name: &str,
+        duration: Uint64,
+        network_type: NetworkType,
+    ) -> Result<Vec<Self>> {
+        let ids = generate_namespace_path(full_name)?;
+
+        let mut transactions = Vec::with_capacity(ids.len());
+        for (depth, name) in full_name.split('.').enumerate() {
+            let tx = if depth == 0 {
+                Self::create_root(deadline, name, duration, network_type)?
+            } else {
+                Self::create_sub(deadline, name, ids[depth - 1], network_type)?
+            };
+            transactions.push(tx);
+        }
+
+        Ok(transactions)
+    }
+
+    /// Creates an aggregate complete transaction that registers every level of a dotted
+    /// name like `a.b.c` for [signer].
+    pub fn create_path_aggregate(
+        deadline: Deadline,
+        full_name: &str,
+        duration: Uint64,
+        signer: PublicAccount,
+        network_type: NetworkType,
+    ) -> Result<AggregateTransaction> {
+        let inner_transactions = Self::create_path(deadline, full_name, duration, network_type)?
+            .into_iter()
+            .map(|mut tx| {
+                tx.set_aggregate(signer);
+                Box::new(tx) as Box<dyn Transaction>
+            })
+            .collect();
+
+        AggregateTransaction::new_complete(deadline, inner_transactions, network_type)
+    }
 }
 
 impl AbsTransaction for RegisterNamespaceTransaction {
@@ -171,6 +246,10 @@ impl Transaction for RegisterNamespaceTransaction {
         self.abs_transaction.set_aggregate(signer)
     }
 
+    fn set_max_fee(&mut self, max_fee: Uint64) {
+        self.abs_transaction.set_max_fee(max_fee)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
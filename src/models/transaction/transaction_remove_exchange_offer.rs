@@ -14,6 +14,7 @@ use crate::{
         exchange::RemoveOffer,
         network::NetworkType,
         transaction::schema::remove_exchange_offer_transaction_schema,
+        uint_64::Uint64,
     },
     Result,
 };
@@ -112,6 +113,10 @@ impl Transaction for RemoveExchangeOfferTransaction {
         self.abs_transaction.set_aggregate(signer)
     }
 
+    fn set_max_fee(&mut self, max_fee: Uint64) {
+        self.abs_transaction.set_max_fee(max_fee)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -14,7 +14,8 @@ use crate::{
         message::Message,
         mosaic::Mosaic,
         namespace::{new_address_from_namespace, NamespaceId},
-        network::NetworkType,
+        network::{NetworkProperties, NetworkType},
+        uint_64::Uint64,
     },
     AssetId, Result,
 };
@@ -64,6 +65,27 @@ impl TransferTransaction {
         })
     }
 
+    /// Creates a transfer on the network described by [properties], checking the deadline
+    /// and the message size against its limits.
+    pub fn new_with_properties(
+        properties: &NetworkProperties,
+        deadline: Deadline,
+        recipient: Address,
+        mosaics: Vec<Mosaic>,
+        message: impl Message + 'static,
+    ) -> Result<Self> {
+        let deadline = properties.validate_deadline(deadline)?;
+        properties.validate_message(&message)?;
+
+        Self::new(
+            deadline,
+            recipient,
+            mosaics,
+            message,
+            properties.network_type,
+        )
+    }
+
     pub fn with_namespace(
         deadline: Deadline,
         recipient: NamespaceId,
@@ -149,7 +171,7 @@ impl Transaction for TransferTransaction {
         let payload_vec = _builder.create_vector_direct(self.message.payload_to_bytes());
 
         let mut message_buffer = buffer::MessageBufferBuilder::new(&mut _builder);
-        message_buffer.add_type_(self.message.message_type().value());
+        message_buffer.add_type_(self.message.message_type_value());
         message_buffer.add_payload(payload_vec);
         let message_vec = message_buffer.finish();
 
@@ -189,6 +211,10 @@ impl Transaction for TransferTransaction {
         self.abs_transaction.set_aggregate(signer)
     }
 
+    fn set_max_fee(&mut self, max_fee: Uint64) {
+        self.abs_transaction.set_max_fee(max_fee)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -26,9 +26,9 @@ pub(crate) struct WsBlockInfoDto {
 impl WsSubscribeDto for WsBlockInfoDto {
     type Output = crate::blockchain::BlockInfo;
 
-    fn compact(self) -> Self::Output {
+    fn compact(self, epoch: i64) -> Self::Output {
         self.block
-            .compact(self.meta.generation_hash, 0, [0, 0])
+            .compact(self.meta.hash, self.meta.generation_hash, 0, [0, 0], epoch)
             .unwrap()
     }
 
@@ -5,7 +5,11 @@
  */
 
 use {
-    ::std::{borrow::Cow, collections::HashMap},
+    ::std::{
+        borrow::Cow,
+        collections::HashMap,
+        sync::{Arc, Mutex, PoisonError},
+    },
     bytes::Bytes,
     downcast_rs::Downcast,
     futures_util::{SinkExt, StreamExt},
@@ -18,10 +22,14 @@ use crate::{
     api::{map_transaction_dto, TransactionDto},
     models::{
         account::Address,
-        blockchain::BlockInfo,
+        blockchain::{BlockInfo, ConfirmationEvent, ConfirmationTracker},
         error::Error,
+        exchange::{OfferEvent, OfferTracker},
         multisig::CosignatureInfo,
-        transaction::{AggregateTransaction, Transaction, TransactionInfo, TransactionStatus},
+        transaction::{
+            AggregateTransaction, Transaction, TransactionInfo, TransactionStatus,
+            TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS,
+        },
         Result,
     },
 };
@@ -47,6 +55,7 @@ pub struct SiriusWebsocketClient {
     uid: WsConnectionResponse,
     conn: WebSocketStream<AutoStream<tokio::net::TcpStream>>,
     handlers: HashMap<String, Box<dyn Handler>>,
+    epoch: i64,
 }
 
 impl SiriusWebsocketClient {
@@ -64,6 +73,34 @@ impl SiriusWebsocketClient {
         Ok(())
     }
 
+    /// Applies every new block to the shared [tracker] and calls [handler_fn] with the
+    /// confirmation events it caused.
+    ///
+    /// Transactions can be added to the tracker at any time through the shared handle.
+    pub async fn add_confirmation_handlers<F>(
+        &mut self,
+        tracker: Arc<Mutex<ConfirmationTracker>>,
+        handler_fn: F,
+    ) -> Result<()>
+    where
+        F: Fn(ConfirmationEvent) -> bool + Send + Sync + 'static,
+    {
+        self.add_block_handlers(move |block| {
+            let events = tracker
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .apply_block(&block);
+
+            // Every event is delivered, even after the handler asked to stop.
+            let mut stop = false;
+            for event in events {
+                stop |= handler_fn(event);
+            }
+            stop
+        })
+        .await
+    }
+
     pub async fn add_status_handlers<F>(&mut self, address: Address, handler_fn: F) -> Result<()>
     where
         F: Fn(TransactionStatus) -> bool + Send + Sync + 'static,
@@ -101,6 +138,43 @@ impl SiriusWebsocketClient {
         Ok(())
     }
 
+    /// Applies the confirmed transactions of the owner of the shared [tracker] and calls
+    /// [handler_fn] with the offer events they caused.
+    ///
+    /// Snapshots and expiries are applied through the shared handle, with
+    /// `OfferTracker::apply_snapshot` and `OfferTracker::expire_at`, and return their own
+    /// events. The handler stops listening when it returns true. It replaces any confirmed
+    /// added handler, since both use the same channel.
+    pub async fn add_exchange_offer_handlers<F>(
+        &mut self,
+        tracker: Arc<Mutex<OfferTracker>>,
+        handler_fn: F,
+    ) -> Result<()>
+    where
+        F: Fn(OfferEvent) -> bool + Send + Sync + 'static,
+    {
+        let address = tracker
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .owner()
+            .address;
+
+        self.add_confirmed_added_handlers(address, move |tx| {
+            let events = tracker
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .apply_transaction(tx.as_ref());
+
+            // Every event is delivered, even after the handler asked to stop.
+            let mut stop = false;
+            for event in events {
+                stop |= handler_fn(event);
+            }
+            stop
+        })
+        .await
+    }
+
     pub async fn add_unconfirmed_removed_handlers<F>(
         &mut self,
         address: Address,
@@ -211,6 +285,15 @@ impl SiriusWebsocketClient {
 
 impl SiriusWebsocketClient {
     pub async fn new<T: AsRef<str>>(url: T) -> Result<SiriusWebsocketClient> {
+        Self::new_with_epoch(url, TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS).await
+    }
+
+    /// Connects to the node and decodes block timestamps and deadlines on the given network
+    /// [epoch], see `NetworkProperties::epoch`.
+    pub async fn new_with_epoch<T: AsRef<str>>(
+        url: T,
+        epoch: i64,
+    ) -> Result<SiriusWebsocketClient> {
         let scheme_str = convert_to_ws_url(url.as_ref())?;
 
         let (mut conn, _) = connect_async(scheme_str).await?;
@@ -225,9 +308,14 @@ impl SiriusWebsocketClient {
             uid,
             conn,
             handlers: HashMap::new(),
+            epoch,
         })
     }
 
+    pub fn epoch(&self) -> i64 {
+        self.epoch
+    }
+
     pub fn uid(&self) -> String {
         self.uid.uid.to_string()
     }
@@ -259,25 +347,25 @@ impl SiriusWebsocketClient {
                 if let Some(base) = self.handlers.get(&channel_name) {
                     if let Some(handler_info) = base.downcast_ref::<HandlerBlock>() {
                         let channel = get_channel_data::<WsBlockInfoDto>(&msg_string, false)?;
-                        if (handler_info.handler)(channel.compact()) {
+                        if (handler_info.handler)(channel.compact(self.epoch)) {
                             break;
                         }
                     } else if let Some(handler_info) = base.downcast_ref::<HandlerStatus>() {
                         let channel = get_channel_data::<WsStatusInfoDto>(&msg_string, false)?;
-                        if (handler_info.handler)(channel.compact()) {
+                        if (handler_info.handler)(channel.compact(self.epoch)) {
                             break;
                         }
                     } else if let Some(handler_info) = base.downcast_ref::<HandlerConfirmedAdd>() {
                         let channel =
                             get_channel_data::<Box<dyn TransactionDto>>(&msg_string, true)?;
-                        if (handler_info.handler)(channel.compact()?) {
+                        if (handler_info.handler)(channel.compact(self.epoch)?) {
                             break;
                         }
                     } else if let Some(handler_info) = base.downcast_ref::<HandlerUnconfirmedAdd>()
                     {
                         let channel =
                             get_channel_data::<Box<dyn TransactionDto>>(&msg_string, true)?;
-                        if (handler_info.handler)(channel.compact()?) {
+                        if (handler_info.handler)(channel.compact(self.epoch)?) {
                             break;
                         }
                     } else if let Some(handler_info) =
@@ -291,7 +379,7 @@ impl SiriusWebsocketClient {
                     } else if let Some(handler_info) = base.downcast_ref::<HandlerPartialAdd>() {
                         let channel =
                             get_channel_data::<Box<dyn TransactionDto>>(&msg_string, true)?;
-                        let tx = channel.compact()?;
+                        let tx = channel.compact(self.epoch)?;
                         let aggregate = tx
                             .try_downcast::<crate::transaction::AggregateTransaction>()
                             .map_err(|_| {
@@ -17,7 +17,7 @@ pub(super) const PATH_STATUS: &RouterPath = "status";
 pub(crate) trait WsSubscribeDto {
     type Output;
 
-    fn compact(self) -> Self::Output;
+    fn compact(self, epoch: i64) -> Self::Output;
 
     fn name(&self) -> &str;
 }
@@ -33,10 +33,10 @@ pub(crate) struct WsStatusInfoDto {
 impl WsSubscribeDto for WsStatusInfoDto {
     type Output = TransactionStatus;
 
-    fn compact(self) -> Self::Output {
+    fn compact(self, epoch: i64) -> Self::Output {
         let blockchain_timestamp = BlockchainTimestamp::new(*self.deadline.compact() as i64);
 
-        let deadline = Deadline::from(blockchain_timestamp);
+        let deadline = Deadline::from_blockchain_timestamp_with_epoch(blockchain_timestamp, epoch);
 
         TransactionStatus {
             group: "".to_string(),